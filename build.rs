@@ -0,0 +1,63 @@
+/// Generates the registry of scaffolded solutions that the main binary runs in-process.
+/// Every `src/bin/YYYY-NN.rs` is included as a module of the main binary and its `Solution` is
+/// registered, so `cargo solve` and `cargo all` don't need to spawn `cargo run` per day.
+use std::{env, fmt::Write, fs, path::Path, process::Command};
+
+fn main() {
+    // the toolchain is recorded alongside benchmark results, see `bench_history`.
+    let rustc = env::var("RUSTC").unwrap_or_else(|_| "rustc".into());
+    let rustc_version = Command::new(rustc)
+        .arg("-V")
+        .output()
+        .ok()
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        .unwrap_or_default();
+    println!("cargo:rustc-env=AOC_RUSTC_VERSION={rustc_version}");
+
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let bin_dir = Path::new(&manifest_dir).join("src").join("bin");
+
+    println!("cargo:rerun-if-changed=src/bin");
+
+    let mut puzzles: Vec<(String, String)> = fs::read_dir(&bin_dir)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| {
+                    let path = entry.ok()?.path();
+                    if path.extension()? != "rs" {
+                        return None;
+                    }
+                    let (year, day) = path.file_stem()?.to_str()?.split_once('-')?;
+                    let is_puzzle = year.len() == 4
+                        && year.parse::<u16>().is_ok_and(|year| year >= 2015)
+                        && day.len() == 2
+                        && day.parse::<u8>().is_ok_and(|day| (1..=25).contains(&day));
+                    is_puzzle.then(|| (year.to_string(), day.to_string()))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    puzzles.sort();
+
+    let mut out = String::from("// @generated by build.rs, do not edit.\n\n");
+
+    for (year, day) in &puzzles {
+        let path = bin_dir.join(format!("{year}-{day}.rs"));
+        writeln!(out, "#[path = {:?}]", path.display().to_string()).unwrap();
+        // the `main` of the solution binary is unused here and crate-level attributes such as
+        // `#![feature(...)]` need to be declared in `src/main.rs` instead.
+        writeln!(out, "#[allow(dead_code, unused_attributes)]").unwrap();
+        writeln!(out, "mod day_{year}_{day};").unwrap();
+    }
+
+    out.push_str("\n/// Every scaffolded solution.\n");
+    out.push_str("pub fn registry() -> advent_of_code::template::registry::Registry {\n");
+    out.push_str("    advent_of_code::template::registry::Registry::new(vec![\n");
+    for (year, day) in &puzzles {
+        writeln!(out, "        &day_{year}_{day}::Solution,").unwrap();
+    }
+    out.push_str("    ])\n}\n");
+
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("solutions.rs");
+    fs::write(out_path, out).unwrap();
+}
@@ -1,6 +1,6 @@
 use std::{collections::HashMap, str::FromStr};
 
-advent_of_code::solution!(2);
+advent_of_code::solution!(2023, 2);
 
 struct Game {
     id: usize,
@@ -89,13 +89,13 @@ mod tests {
     #[test]
     #[ignore]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, None);
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(2286));
     }
 }
@@ -1,6 +1,6 @@
 use std::{collections::HashMap, iter, ops::RangeInclusive};
 
-advent_of_code::solution!(3);
+advent_of_code::solution!(2023, 3);
 
 struct Number {
     value: u32,
@@ -171,14 +171,14 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(4361));
     }
 
     #[test]
     #[ignore]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, None);
     }
 }
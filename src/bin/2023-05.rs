@@ -5,7 +5,7 @@ use std::{
     str::{FromStr, Lines},
 };
 
-advent_of_code::solution!(5);
+advent_of_code::solution!(2023, 5);
 
 #[derive(Debug)]
 struct Map {
@@ -242,13 +242,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(35));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(46));
     }
 }
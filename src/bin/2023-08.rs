@@ -1,6 +1,6 @@
 use std::{collections::HashMap, mem};
 
-advent_of_code::solution!(8);
+advent_of_code::solution!(2023, 8, parse);
 
 #[derive(Copy, Clone, Debug)]
 enum Inst {
@@ -8,7 +8,12 @@ enum Inst {
     R,
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
+pub struct Map<'a> {
+    instructions: Vec<Inst>,
+    network: HashMap<&'a str, (&'a str, &'a str)>,
+}
+
+pub fn parse(input: &str) -> Map<'_> {
     let mut lines = input.lines();
     let instructions = lines
         .next()
@@ -36,6 +41,18 @@ pub fn part_one(input: &str) -> Option<u32> {
         })
         .collect();
 
+    Map {
+        instructions,
+        network,
+    }
+}
+
+pub fn part_one(map: &Map) -> Option<u32> {
+    let Map {
+        instructions,
+        network,
+    } = map;
+
     let mut current = "AAA";
     let mut insts = instructions.iter().cycle().enumerate();
 
@@ -49,33 +66,11 @@ pub fn part_one(input: &str) -> Option<u32> {
     Some(insts.next().unwrap().0 as u32)
 }
 
-pub fn part_two(input: &str) -> Option<u128> {
-    let mut lines = input.lines();
-    let instructions = lines
-        .next()
-        .unwrap()
-        .chars()
-        .map(|c| match c {
-            'L' => Inst::L,
-            'R' => Inst::R,
-            _ => panic!("not L or R"),
-        })
-        .collect::<Vec<_>>();
-
-    // Skip blank
-    let _ = lines.next();
-
-    let network: HashMap<&str, (&str, &str)> = lines
-        .map(|line| {
-            let (key, pair_raw) = line.split_once(" = ").unwrap();
-            let pair = pair_raw
-                .strip_prefix('(')
-                .and_then(|s| s.strip_suffix(')'))
-                .and_then(|s| s.split_once(", "))
-                .unwrap();
-            (key, pair)
-        })
-        .collect();
+pub fn part_two(map: &Map) -> Option<u128> {
+    let Map {
+        instructions,
+        network,
+    } = map;
 
     let starts = network.keys().filter(|key| key.ends_with('A')).copied();
     let steps = starts.map(|start| {
@@ -124,25 +119,25 @@ mod tests {
 
     #[test]
     fn test_part_one_0() {
-        let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 0,
-        ));
+        let result = part_one(&parse(&advent_of_code::template::read_file_part(
+            "examples", PUZZLE, 0,
+        )));
         assert_eq!(result, Some(2));
     }
 
     #[test]
     fn test_part_one_1() {
-        let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 1,
-        ));
+        let result = part_one(&parse(&advent_of_code::template::read_file_part(
+            "examples", PUZZLE, 1,
+        )));
         assert_eq!(result, Some(6));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
-        ));
+        let result = part_two(&parse(&advent_of_code::template::read_file_part(
+            "examples", PUZZLE, 2,
+        )));
         assert_eq!(result, Some(6));
     }
 }
@@ -2,7 +2,7 @@
 
 use std::str::FromStr;
 
-advent_of_code::solution!(9);
+advent_of_code::solution!(2023, 9);
 
 fn predict_next(nums: &[i64]) -> i64 {
     if nums.iter().all(|&n| n == 0) {
@@ -66,13 +66,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(114));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(2));
     }
 }
@@ -1,7 +1,7 @@
 use core::fmt;
 use std::{collections::HashSet, iter, mem};
 
-advent_of_code::solution!(10);
+advent_of_code::solution!(2023, 10, grid_and_loop);
 
 fn some_if<T, F>(t: T, predicate: F) -> Option<T>
 where
@@ -15,7 +15,7 @@ where
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
-enum Tile {
+pub enum Tile {
     Pipe,
     Dash,
     L,
@@ -102,7 +102,10 @@ fn adjacent_to(
     .filter_map(|(x, y)| x.zip(y))
 }
 
-fn grid_and_loop(input: &str) -> (Vec<Vec<Tile>>, Vec<(usize, usize)>) {
+/// The tiles of the grid along with the coordinates of the loop through the start.
+pub type GridAndLoop = (Vec<Vec<Tile>>, Vec<(usize, usize)>);
+
+fn grid_and_loop(input: &str) -> GridAndLoop {
     let mut start = None;
     let mut grid = input
         .lines()
@@ -176,8 +179,7 @@ fn grid_and_loop(input: &str) -> (Vec<Vec<Tile>>, Vec<(usize, usize)>) {
     (grid, loop_)
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let (_grid, loop_) = grid_and_loop(input);
+pub fn part_one((_grid, loop_): &GridAndLoop) -> Option<u32> {
     Some(loop_.len() as u32 / 2)
 }
 
@@ -259,7 +261,7 @@ impl fmt::Display for SearchTile {
     }
 }
 
-fn explode_grid(orig: Vec<Vec<Tile>>) -> Vec<Vec<SearchTile>> {
+fn explode_grid(orig: &[Vec<Tile>]) -> Vec<Vec<SearchTile>> {
     let rows = orig.len();
     let cols = orig[0].len();
 
@@ -387,10 +389,8 @@ fn paint_grid(
     grid
 }
 
-pub fn part_two(input: &str) -> Option<usize> {
-    let (grid, loop_) = grid_and_loop(input);
-
-    let loop_coords = loop_.into_iter().collect::<HashSet<_>>();
+pub fn part_two((grid, loop_): &GridAndLoop) -> Option<usize> {
+    let loop_coords = loop_.iter().copied().collect::<HashSet<_>>();
     let in_orig_loop = move |(x, y)| loop_coords.contains(&(x, y));
 
     // print_grid(&grid);
@@ -445,31 +445,33 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&grid_and_loop(&advent_of_code::template::read_file(
+            "examples", PUZZLE,
+        )));
         assert_eq!(result, Some(8));
     }
 
     #[test]
     fn test_part_two_simple() {
-        let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 1,
-        ));
+        let result = part_two(&grid_and_loop(&advent_of_code::template::read_file_part(
+            "examples", PUZZLE, 1,
+        )));
         assert_eq!(result, Some(4));
     }
 
     #[test]
     fn test_part_two_larger() {
-        let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
-        ));
+        let result = part_two(&grid_and_loop(&advent_of_code::template::read_file_part(
+            "examples", PUZZLE, 2,
+        )));
         assert_eq!(result, Some(8));
     }
 
     #[test]
     fn test_part_two_random_tiles() {
-        let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 3,
-        ));
+        let result = part_two(&grid_and_loop(&advent_of_code::template::read_file_part(
+            "examples", PUZZLE, 3,
+        )));
         assert_eq!(result, Some(10));
     }
 }
@@ -1,6 +1,6 @@
 use std::{collections::HashSet, mem};
 
-advent_of_code::solution!(11);
+advent_of_code::solution!(2023, 11);
 
 fn parse(input: &str) -> Vec<Vec<char>> {
     input
@@ -151,22 +151,22 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(374));
     }
 
     #[test]
     fn part_one_expand() {
-        let expanded = expand(&advent_of_code::template::read_file("examples", DAY));
+        let expanded = expand(&advent_of_code::template::read_file("examples", PUZZLE));
         let parsed = parse(&advent_of_code::template::read_file_part(
-            "examples", DAY, 1,
+            "examples", PUZZLE, 1,
         ));
         assert_eq!(expanded, parsed);
     }
 
     #[test]
     fn test_part_two_10() {
-        let result = part_two_inner(&advent_of_code::template::read_file("examples", DAY), 10);
+        let result = part_two_inner(&advent_of_code::template::read_file("examples", PUZZLE), 10);
         assert_eq!(result, 1030);
     }
 }
@@ -1,4 +1,15 @@
-use std::{error::Error, fmt::Display, str::FromStr};
+use std::{
+    error::Error,
+    fmt::Display,
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{Puzzle, Year};
+
+/// Puzzles unlock at midnight EST (UTC-5), i.e. at 05:00 UTC.
+pub(crate) const UNLOCK_OFFSET_SECS: u64 = 5 * 60 * 60;
+pub(crate) const SECS_PER_DAY: u64 = 24 * 60 * 60;
 
 /// A valid day number of advent (i.e. an integer in range 1 to 25).
 ///
@@ -33,6 +44,53 @@ impl Day {
     pub fn into_inner(self) -> u8 {
         self.0
     }
+
+    /// Returns the day whose puzzle unlocked most recently, if it is currently advent (December
+    /// 1st to 25th in EST, the timezone puzzles unlock in), [`None`] otherwise. See
+    /// [`Puzzle::today`] for its year.
+    pub fn today() -> Option<Self> {
+        Puzzle::today().map(|puzzle| puzzle.day)
+    }
+
+    /// Returns the time at which the puzzle of this day unlocks in `year`: midnight EST on the
+    /// day in December.
+    ///
+    /// ```
+    /// # use std::time::{Duration, UNIX_EPOCH};
+    /// # use advent_of_code::{day, year};
+    /// // 2023-12-01T05:00:00Z
+    /// let unlock = day!(1).unlocks_at(year!(2023));
+    /// assert_eq!(unlock, UNIX_EPOCH + Duration::from_secs(1_701_406_800));
+    /// ```
+    pub fn unlocks_at(self, year: Year) -> SystemTime {
+        let year = u64::from(year.into_inner());
+        let is_leap_year = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+        let leap_days = |y: u64| y / 4 - y / 100 + y / 400;
+
+        // days from 1970-01-01 to december 1st of `year`.
+        let december = (year - 1970) * 365 + leap_days(year - 1) - leap_days(1969)
+            + 334
+            + u64::from(is_leap_year);
+        let days = december + u64::from(self.0) - 1;
+
+        UNIX_EPOCH + Duration::from_secs(days * SECS_PER_DAY + UNLOCK_OFFSET_SECS)
+    }
+}
+
+/// Converts days since the unix epoch to a year, month and day of the month, see
+/// <http://howardhinnant.github.io/date_algorithms.html#civil_from_days>.
+#[allow(clippy::cast_possible_truncation)]
+pub(crate) fn civil_from_days(days: u64) -> (u16, u8, u8) {
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z % 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + u64::from(month <= 2);
+    (year as u16, month as u8, day as u8)
 }
 
 impl Display for Day {
@@ -132,7 +190,30 @@ macro_rules! day {
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{all_days, Day};
+    use std::time::UNIX_EPOCH;
+
+    use super::{all_days, civil_from_days, Day};
+    use crate::year;
+
+    #[test]
+    fn computes_unlock_times() {
+        let secs = |day: Day, year| {
+            day.unlocks_at(year)
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+        };
+        // 2023-12-01T05:00:00Z, 2020-12-25T05:00:00Z and 2024-12-01T05:00:00Z.
+        assert_eq!(secs(Day(1), year!(2023)).ok(), Some(1_701_406_800));
+        assert_eq!(secs(Day(25), year!(2020)).ok(), Some(1_608_872_400));
+        assert_eq!(secs(Day(1), year!(2024)).ok(), Some(1_733_029_200));
+    }
+
+    #[test]
+    fn converts_days_to_dates() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(19_692), (2023, 12, 1));
+        assert_eq!(civil_from_days(19_782), (2024, 2, 29));
+    }
 
     #[test]
     fn all_days_iterator() {
@@ -1,55 +1,196 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve};
+// Solutions are compiled into this binary as well (see `build.rs`), so unstable features used by
+// any of them need to be enabled here.
+#![feature(array_chunks, array_windows)]
+
+use std::time::Duration;
+
+use advent_of_code::template::{
+    commands::{
+        all, bench_diff, download, examples, gen_tests, leaderboard, list, read, scaffold, solve,
+        stars, unscaffold, verify,
+    },
+    runner::{Input, RunOptions},
+};
 use args::{parse, AppArguments};
 
+mod solutions {
+    include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+}
+
 mod args {
     use std::process;
 
-    use advent_of_code::Day;
+    use advent_of_code::{
+        template::{
+            aoc_client::get_year, commands::scaffold, records::OutputFormat, runner::Input,
+        },
+        Day, Puzzle, Year,
+    };
 
     pub enum AppArguments {
         Download {
-            day: Day,
+            puzzle: Puzzle,
+            wait: bool,
+        },
+        Examples {
+            puzzle: Puzzle,
+        },
+        GenTests {
+            puzzle: Puzzle,
+        },
+        Leaderboard {
+            year: Year,
+            id: u64,
+            day: Option<Day>,
+            cached: bool,
         },
         Read {
-            day: Day,
+            puzzle: Puzzle,
+            refresh: bool,
         },
         Scaffold {
-            day: Day,
+            puzzle: Puzzle,
+            template: String,
+            return_type: String,
+            missing_only: bool,
+        },
+        ScaffoldCheck {
+            year: Year,
         },
         Solve {
-            day: Day,
-            release: bool,
+            puzzle: Puzzle,
             time: bool,
+            reject_outliers: bool,
+            format: OutputFormat,
             submit: Option<u8>,
+            force: bool,
+            wait: bool,
+            input: Option<Input>,
+            examples: bool,
         },
         All {
-            release: bool,
+            year: Year,
             time: bool,
+            reject_outliers: bool,
+            format: OutputFormat,
+            timeout: Option<u64>,
+            jobs: usize,
+        },
+        BenchDiff {
+            year: Year,
+            rev: Option<String>,
+            threshold: f64,
+        },
+        List {
+            year: Option<Year>,
         },
+        Stars,
+        Unscaffold {
+            puzzle: Puzzle,
+            yes: bool,
+        },
+        Verify {
+            year: Year,
+            day: Option<Day>,
+        },
+    }
+
+    /// Reads the day of a puzzle. During advent it defaults to the puzzle that unlocked most
+    /// recently, whose year takes precedence over `AOC_YEAR`. Passing `--year` for another year
+    /// requires the day.
+    fn puzzle_or_today(
+        args: &mut pico_args::Arguments,
+        year: Option<Year>,
+    ) -> Result<Puzzle, Box<dyn std::error::Error>> {
+        if let Some(day) = args.opt_free_from_str()? {
+            return Ok(Puzzle::new(year.map_or_else(get_year, Ok)?, day));
+        }
+        match Puzzle::today() {
+            Some(today) if year.unwrap_or(today.year) == today.year => Ok(today),
+            _ => Err("no day given. Pass a day number.".into()),
+        }
     }
 
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
+        let subcommand = args.subcommand()?;
+
+        // options have to be parsed before the day, which is a free argument.
+        let year: Option<Year> = args.opt_value_from_str("--year")?;
+        let default_year = || year.map_or_else(get_year, Ok);
+        // `--release` used to pick the profile solutions were spawned with, they run in-process
+        // now. Keep accepting it so existing scripts don't warn about it.
+        if matches!(subcommand.as_deref(), Some("all" | "solve")) {
+            args.contains("--release");
+        }
 
-        let app_args = match args.subcommand()?.as_deref() {
+        let app_args = match subcommand.as_deref() {
             Some("all") => AppArguments::All {
-                release: args.contains("--release"),
+                year: default_year()?,
                 time: args.contains("--time"),
+                reject_outliers: args.contains("--reject-outliers"),
+                format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+                timeout: args.opt_value_from_str("--timeout")?,
+                jobs: args.opt_value_from_str("--jobs")?.unwrap_or(1),
+            },
+            Some("bench-diff") => AppArguments::BenchDiff {
+                year: default_year()?,
+                threshold: args.opt_value_from_str("--threshold")?.unwrap_or(10.0),
+                rev: args.opt_free_from_str()?,
             },
             Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
+                wait: args.contains("--wait"),
+                puzzle: puzzle_or_today(&mut args, year)?,
+            },
+            Some("examples") => AppArguments::Examples {
+                puzzle: puzzle_or_today(&mut args, year)?,
+            },
+            Some("gen-tests") => AppArguments::GenTests {
+                puzzle: puzzle_or_today(&mut args, year)?,
+            },
+            Some("leaderboard") => AppArguments::Leaderboard {
+                year: default_year()?,
+                day: args.opt_value_from_str("--day")?,
+                cached: args.contains("--cached"),
+                id: args.free_from_str()?,
             },
+            Some("list") => AppArguments::List { year },
             Some("read") => AppArguments::Read {
-                day: args.free_from_str()?,
+                refresh: args.contains("--refresh"),
+                puzzle: puzzle_or_today(&mut args, year)?,
+            },
+            Some("scaffold") if args.contains("--check") => AppArguments::ScaffoldCheck {
+                year: default_year()?,
             },
             Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
+                missing_only: args.contains("--missing-only"),
+                template: args
+                    .opt_value_from_str("--template")?
+                    .unwrap_or_else(|| scaffold::DEFAULT_TEMPLATE.into()),
+                return_type: args
+                    .opt_value_from_str("--return-type")?
+                    .unwrap_or_else(|| scaffold::DEFAULT_RETURN_TYPE.into()),
+                puzzle: puzzle_or_today(&mut args, year)?,
             },
             Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
-                release: args.contains("--release"),
                 submit: args.opt_value_from_str("--submit")?,
+                force: args.contains("--force"),
+                wait: args.contains("--wait"),
+                input: args.opt_value_from_str("--input")?,
+                examples: args.contains("--examples"),
                 time: args.contains("--time"),
+                reject_outliers: args.contains("--reject-outliers"),
+                format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+                puzzle: puzzle_or_today(&mut args, year)?,
+            },
+            Some("stars") => AppArguments::Stars,
+            Some("unscaffold") => AppArguments::Unscaffold {
+                yes: args.contains("--yes"),
+                puzzle: puzzle_or_today(&mut args, year)?,
+            },
+            Some("verify") => AppArguments::Verify {
+                year: default_year()?,
+                day: args.opt_free_from_str()?,
             },
             Some(x) => {
                 eprintln!("Unknown command: {x}");
@@ -77,16 +218,84 @@ fn main() {
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release, time } => all::handle(release, time),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
-            AppArguments::Scaffold { day } => scaffold::handle(day),
-            AppArguments::Solve {
+            AppArguments::All {
+                year,
+                time,
+                reject_outliers,
+                format,
+                timeout,
+                jobs,
+            } => all::handle(
+                &solutions::registry(),
+                year,
+                &RunOptions {
+                    time,
+                    reject_outliers,
+                    format,
+                    submit: None,
+                    force: false,
+                    wait: false,
+                    input: Input::Puzzle,
+                    quiet: false,
+                },
+                timeout.map(Duration::from_secs),
+                jobs,
+            ),
+            AppArguments::BenchDiff {
+                year,
+                rev,
+                threshold,
+            } => {
+                bench_diff::handle(year, rev.as_deref(), threshold);
+            }
+            AppArguments::Download { puzzle, wait } => download::handle(puzzle, wait),
+            AppArguments::Examples { puzzle } => examples::handle(puzzle),
+            AppArguments::GenTests { puzzle } => gen_tests::handle(puzzle),
+            AppArguments::Leaderboard {
+                year,
+                id,
                 day,
-                release,
+                cached,
+            } => leaderboard::handle(year, id, day, cached),
+            AppArguments::List { year } => list::handle(&solutions::registry(), year),
+            AppArguments::Read { puzzle, refresh } => read::handle(puzzle, refresh),
+            AppArguments::Scaffold {
+                puzzle,
+                template,
+                return_type,
+                missing_only,
+            } => scaffold::handle(puzzle, &template, &return_type, missing_only),
+            AppArguments::ScaffoldCheck { year } => scaffold::check(year),
+            AppArguments::Solve {
+                puzzle,
                 time,
+                reject_outliers,
+                format,
                 submit,
-            } => solve::handle(day, release, time, submit),
+                force,
+                wait,
+                input,
+                examples,
+            } => solve::handle(
+                &solutions::registry(),
+                puzzle,
+                &RunOptions {
+                    time,
+                    reject_outliers,
+                    format,
+                    submit,
+                    force,
+                    wait,
+                    input: input.unwrap_or_default(),
+                    quiet: false,
+                },
+                examples,
+            ),
+            AppArguments::Stars => stars::handle(),
+            AppArguments::Unscaffold { puzzle, yes } => unscaffold::handle(puzzle, yes),
+            AppArguments::Verify { year, day } => {
+                verify::handle(&solutions::registry(), year, day);
+            }
         },
     };
 }
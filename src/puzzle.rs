@@ -0,0 +1,86 @@
+use std::{
+    fmt::Display,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::{civil_from_days, Day, Year, SECS_PER_DAY, UNLOCK_OFFSET_SECS};
+
+/// Identifies a puzzle by the year and day it was released.
+///
+/// # Display
+/// This value displays as the year and day, which is also the name of the solution binary.
+///
+/// ```
+/// # use advent_of_code::{day, year, Puzzle};
+/// let puzzle = Puzzle::new(year!(2023), day!(8));
+/// assert_eq!(puzzle.to_string(), "2023-08")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Puzzle {
+    pub year: Year,
+    pub day: Day,
+}
+
+impl Puzzle {
+    pub const fn new(year: Year, day: Day) -> Self {
+        Self { year, day }
+    }
+
+    /// Returns the puzzle that unlocked most recently, if it is currently advent (December 1st
+    /// to 25th in EST, the timezone puzzles unlock in), [`None`] otherwise.
+    pub fn today() -> Option<Self> {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).ok()?;
+        Self::at_timestamp(now.as_secs())
+    }
+
+    /// Returns the puzzle of advent at a point in time, given in seconds since the unix epoch.
+    fn at_timestamp(timestamp: u64) -> Option<Self> {
+        let days = timestamp.checked_sub(UNLOCK_OFFSET_SECS)? / SECS_PER_DAY;
+        match civil_from_days(days) {
+            (year, 12, day) => Some(Self::new(Year::new(year)?, Day::new(day)?)),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Puzzle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.year, self.day)
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::UNIX_EPOCH;
+
+    use super::Puzzle;
+    use crate::{day, year, Day, Year};
+
+    #[test]
+    fn finds_puzzle_of_advent() {
+        let unlock = |year: Year, day: Day| {
+            day.unlocks_at(year)
+                .duration_since(UNIX_EPOCH)
+                .unwrap()
+                .as_secs()
+        };
+        let puzzle = |year, day| Some(Puzzle::new(year, day));
+
+        let first = unlock(year!(2023), day!(1));
+        assert_eq!(Puzzle::at_timestamp(first), puzzle(year!(2023), day!(1)));
+        assert_eq!(Puzzle::at_timestamp(first - 1), None);
+        assert_eq!(
+            Puzzle::at_timestamp(unlock(year!(2023), day!(12)) + 86_399),
+            puzzle(year!(2023), day!(12))
+        );
+        assert_eq!(
+            Puzzle::at_timestamp(unlock(year!(2023), day!(25)) + 86_400),
+            None
+        );
+        assert_eq!(Puzzle::at_timestamp(first - 200 * 86_400), None);
+        assert_eq!(
+            Puzzle::at_timestamp(unlock(year!(2026), day!(3))),
+            puzzle(year!(2026), day!(3))
+        );
+    }
+}
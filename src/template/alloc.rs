@@ -0,0 +1,177 @@
+/// Counts heap allocations made by solution parts.
+///
+/// Counting is opt-in: the [`CountingAllocator`] is only installed as the global allocator when
+/// the `alloc_stats` feature is enabled, e.g. `cargo run --release --features alloc_stats -- solve 1`.
+/// Without it, [`measure`] reports no stats.
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    fmt::Display,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+#[cfg(feature = "alloc_stats")]
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED_BYTES: AtomicU64 = AtomicU64::new(0);
+static LIVE_BYTES: AtomicU64 = AtomicU64::new(0);
+static PEAK_LIVE_BYTES: AtomicU64 = AtomicU64::new(0);
+
+/// Wraps the system allocator and keeps track of every allocation.
+pub struct CountingAllocator;
+
+impl CountingAllocator {
+    fn track_alloc(size: usize) {
+        let size = size as u64;
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(size, Ordering::Relaxed);
+        let live = LIVE_BYTES.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK_LIVE_BYTES.fetch_max(live, Ordering::Relaxed);
+    }
+
+    fn track_dealloc(size: usize) {
+        LIVE_BYTES.fetch_sub(size as u64, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            Self::track_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            Self::track_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        Self::track_dealloc(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            // a reallocation counts as a new allocation that replaces the old one.
+            Self::track_dealloc(layout.size());
+            Self::track_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// Heap usage of a single run of a solution part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct AllocStats {
+    /// Number of allocations, including reallocations.
+    pub count: u64,
+    /// Total number of bytes allocated.
+    pub bytes: u64,
+    /// Highest number of bytes that were allocated at the same time, on top of what was
+    /// allocated before the run started.
+    pub peak_bytes: u64,
+}
+
+impl Display for AllocStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} allocs, {} total, {} peak",
+            self.count,
+            format_bytes(self.bytes),
+            format_bytes(self.peak_bytes)
+        )
+    }
+}
+
+/// Whether allocations are being counted, i.e. the `alloc_stats` feature is enabled.
+#[must_use]
+pub const fn is_enabled() -> bool {
+    cfg!(feature = "alloc_stats")
+}
+
+/// Runs `func` and reports the allocations it made, if counting is enabled.
+pub fn measure<T>(func: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    if !is_enabled() {
+        return (func(), None);
+    }
+
+    let base_allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let base_bytes = ALLOCATED_BYTES.load(Ordering::Relaxed);
+    let base_live = LIVE_BYTES.load(Ordering::Relaxed);
+    PEAK_LIVE_BYTES.store(base_live, Ordering::Relaxed);
+
+    let result = func();
+
+    let stats = AllocStats {
+        count: ALLOCATIONS.load(Ordering::Relaxed) - base_allocations,
+        bytes: ALLOCATED_BYTES.load(Ordering::Relaxed) - base_bytes,
+        peak_bytes: PEAK_LIVE_BYTES
+            .load(Ordering::Relaxed)
+            .saturating_sub(base_live),
+    };
+
+    (result, Some(stats))
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = UNITS[0];
+    for next in &UNITS[1..] {
+        if value < 1024.0 {
+            break;
+        }
+        value /= 1024.0;
+        unit = next;
+    }
+
+    format!("{value:.1} {unit}")
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{format_bytes, AllocStats};
+
+    #[test]
+    #[cfg(feature = "alloc_stats")]
+    fn counts_allocations() {
+        let (v, stats) = super::measure(|| vec![0_u8; 4096]);
+        let stats = stats.unwrap();
+        assert_eq!(v.len(), 4096);
+        assert_eq!(stats.count >= 1, true);
+        assert_eq!(stats.bytes >= 4096, true);
+    }
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+
+    #[test]
+    fn displays_stats() {
+        let stats = AllocStats {
+            count: 12,
+            bytes: 2048,
+            peak_bytes: 512,
+        };
+        assert_eq!(stats.to_string(), "12 allocs, 2.0 KiB total, 512 B peak");
+    }
+}
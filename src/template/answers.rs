@@ -0,0 +1,235 @@
+/// Module that stores accepted answers per day in `data/<year>/answers/NN.toml`, e.g.:
+///
+/// ```toml
+/// part_one = "1234"
+/// part_two = "5678"
+/// ```
+///
+/// Answers are recorded after a correct submission and can also be added by hand.
+use std::{fmt::Display, fs, io, path::PathBuf};
+
+use crate::{template::get_data_path, Puzzle};
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "could not parse answers: {e}"),
+            Error::IO(e) => write!(f, "could not access answers: {e}"),
+        }
+    }
+}
+
+/// How an answer compares to the accepted answer for its part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Matches,
+    Differs,
+    Unknown,
+}
+
+impl Verdict {
+    #[must_use]
+    pub fn of(answer: Option<&str>, expected: Option<&str>) -> Self {
+        match expected {
+            None => Self::Unknown,
+            Some(expected) if answer == Some(expected) => Self::Matches,
+            Some(_) => Self::Differs,
+        }
+    }
+
+    /// A short marker for human-readable output.
+    #[must_use]
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Self::Matches => "✓",
+            Self::Differs => "✗",
+            Self::Unknown => "?",
+        }
+    }
+}
+
+/// The accepted answers of a day.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+}
+
+impl Answers {
+    #[must_use]
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_one.as_deref(),
+            2 => self.part_two.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn set(&mut self, part: u8, answer: String) {
+        match part {
+            1 => self.part_one = Some(answer),
+            2 => self.part_two = Some(answer),
+            _ => {}
+        }
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.part_one.is_none() && self.part_two.is_none()
+    }
+
+    pub fn parse(s: &str) -> Result<Self, Error> {
+        let mut answers = Self::default();
+
+        for line in s.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| Error::Parser(format!("expected `key = \"value\"`: {line}")))?;
+
+            let value = unquote(value.trim())
+                .ok_or_else(|| Error::Parser(format!("expected a quoted string: {line}")))?;
+
+            match key.trim() {
+                "part_one" => answers.part_one = Some(value),
+                "part_two" => answers.part_two = Some(value),
+                key => return Err(Error::Parser(format!("unknown key `{key}`"))),
+            }
+        }
+
+        Ok(answers)
+    }
+
+    #[must_use]
+    pub fn to_toml(&self) -> String {
+        let mut s = String::new();
+        if let Some(answer) = &self.part_one {
+            s.push_str(&format!("part_one = {}\n", quote(answer)));
+        }
+        if let Some(answer) = &self.part_two {
+            s.push_str(&format!("part_two = {}\n", quote(answer)));
+        }
+        s
+    }
+}
+
+/// Writes a TOML basic string.
+fn quote(s: &str) -> String {
+    let mut quoted = String::from('"');
+    for c in s.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\t' => quoted.push_str("\\t"),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Reads a TOML basic string, returns [`None`] if `s` is not one.
+fn unquote(s: &str) -> Option<String> {
+    let inner = s.strip_prefix('"')?.strip_suffix('"')?;
+    let mut unquoted = String::new();
+    let mut chars = inner.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next()? {
+                '"' => unquoted.push('"'),
+                '\\' => unquoted.push('\\'),
+                'n' => unquoted.push('\n'),
+                't' => unquoted.push('\t'),
+                _ => return None,
+            },
+            '"' => return None,
+            c => unquoted.push(c),
+        }
+    }
+
+    Some(unquoted)
+}
+
+#[must_use]
+pub fn get_path(puzzle: Puzzle) -> PathBuf {
+    get_data_path(puzzle.year, "answers", &format!("{}.toml", puzzle.day))
+}
+
+/// Reads the accepted answers of a puzzle. Puzzles without an answers file have no known answers.
+pub fn read(puzzle: Puzzle) -> Result<Answers, Error> {
+    let path = get_path(puzzle);
+    if !path.exists() {
+        return Ok(Answers::default());
+    }
+    Answers::parse(&fs::read_to_string(path)?)
+}
+
+pub fn write(puzzle: Puzzle, answers: &Answers) -> Result<(), Error> {
+    let path = get_path(puzzle);
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, answers.to_toml())?;
+    Ok(())
+}
+
+/// Stores `answer` as the accepted answer for a part.
+pub fn record(puzzle: Puzzle, part: u8, answer: &str) -> Result<(), Error> {
+    let mut answers = read(puzzle)?;
+    answers.set(part, answer.to_string());
+    write(puzzle, &answers)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Answers, Verdict};
+
+    #[test]
+    fn parses_answers() {
+        let answers =
+            Answers::parse("# day 1\npart_one = \"42\"\n\npart_two=\"a \\\"b\\\"\\nc\"\n").unwrap();
+        assert_eq!(answers.get(1), Some("42"));
+        assert_eq!(answers.get(2), Some("a \"b\"\nc"));
+    }
+
+    #[test]
+    fn roundtrips_answers() {
+        let answers = Answers {
+            part_one: Some("1234".into()),
+            part_two: Some("multi\nline \\ \"answer\"".into()),
+        };
+        assert_eq!(Answers::parse(&answers.to_toml()).unwrap(), answers);
+    }
+
+    #[test]
+    fn rejects_malformed_answers() {
+        assert!(Answers::parse("part_one = 42").is_err());
+        assert!(Answers::parse("part_three = \"42\"").is_err());
+        assert!(Answers::parse("part_one").is_err());
+    }
+
+    #[test]
+    fn compares_answers() {
+        assert_eq!(Verdict::of(Some("42"), Some("42")), Verdict::Matches);
+        assert_eq!(Verdict::of(Some("41"), Some("42")), Verdict::Differs);
+        assert_eq!(Verdict::of(None, Some("42")), Verdict::Differs);
+        assert_eq!(Verdict::of(Some("42"), None), Verdict::Unknown);
+    }
+}
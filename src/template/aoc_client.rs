@@ -0,0 +1,492 @@
+/// A small client for the Advent of Code website, used to download inputs, puzzle descriptions
+/// and private leaderboards and to submit answers.
+///
+/// The client authenticates with the session cookie of a logged-in browser, which is read from
+/// the `AOC_SESSION` environment variable or from a file: `AOC_SESSION_FILE` if set, otherwise
+/// `~/.adventofcode.session` (the same file aoc-cli uses). Requests go to `AOC_BASE_URL`, which
+/// defaults to `https://adventofcode.com`.
+///
+/// Commands work on the year given with `--year`, falling back to `AOC_YEAR`, see [`get_year`].
+use std::{env, fmt::Display, fs, io, path::PathBuf, time::Duration};
+
+use crate::{template::html, Puzzle, Year};
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Identifies this tool to the Advent of Code servers, as requested by its author.
+const USER_AGENT: &str = "advent_of_code (+https://github.com/fspoettel/advent-of-code-rust)";
+
+#[derive(Debug)]
+pub enum Error {
+    MissingSession,
+    MissingYear,
+    InvalidYear(String),
+    /// The session cookie was rejected.
+    Unauthorized,
+    /// The puzzle does not exist or is not unlocked yet.
+    NotFound,
+    Status(u16),
+    Transport(String),
+    IO(io::Error),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::MissingSession => write!(
+                f,
+                "no session cookie found. Set AOC_SESSION or save the cookie to \"{}\".",
+                session_file_path().map_or("~/.adventofcode.session".into(), |p| p
+                    .display()
+                    .to_string())
+            ),
+            Error::MissingYear => {
+                write!(f, "no year given. Pass --year or set AOC_YEAR.")
+            }
+            Error::InvalidYear(year) => write!(
+                f,
+                "AOC_YEAR \"{year}\" is not a valid year, expecting a four digit year, 2015 or \
+                 later."
+            ),
+            Error::Unauthorized => write!(f, "the session cookie is invalid or has expired."),
+            Error::NotFound => write!(f, "the puzzle is not available (yet)."),
+            Error::Status(status) => write!(f, "unexpected response status {status}."),
+            Error::Transport(e) => write!(f, "request failed: {e}"),
+            Error::IO(e) => write!(f, "i/o error: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl From<ureq::Error> for Error {
+    fn from(e: ureq::Error) -> Self {
+        match e {
+            // inputs answer with 400 when the session is missing or wrong.
+            ureq::Error::Status(400 | 401, _) => Error::Unauthorized,
+            ureq::Error::Status(404, _) => Error::NotFound,
+            ureq::Error::Status(status, _) => Error::Status(status),
+            ureq::Error::Transport(e) => Error::Transport(e.to_string()),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// How Advent of Code judged a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// An answer was submitted too recently, nothing was checked.
+    RateLimited,
+    /// The part was already solved, nothing was checked.
+    AlreadySolved,
+    /// The response could not be recognized, holds its text.
+    Unknown(String),
+}
+
+impl Outcome {
+    /// Reads the outcome from the response page of a submission.
+    #[must_use]
+    pub fn parse(html: &str) -> Self {
+        let text = html::article_text(html);
+
+        if text.contains("That's the right answer") {
+            Outcome::Correct
+        } else if text.contains("You gave an answer too recently") {
+            Outcome::RateLimited
+        } else if text.contains("your answer is too high") {
+            Outcome::TooHigh
+        } else if text.contains("your answer is too low") {
+            Outcome::TooLow
+        } else if text.contains("That's not the right answer") {
+            Outcome::Wrong
+        } else if text.contains("You don't seem to be solving the right level") {
+            Outcome::AlreadySolved
+        } else {
+            Outcome::Unknown(text)
+        }
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::Wrong => write!(f, "wrong"),
+            Outcome::TooHigh => write!(f, "too high"),
+            Outcome::TooLow => write!(f, "too low"),
+            Outcome::RateLimited => write!(f, "rate-limited"),
+            Outcome::AlreadySolved => write!(f, "already solved"),
+            Outcome::Unknown(_) => write!(f, "unknown"),
+        }
+    }
+}
+
+/// The response to a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub outcome: Outcome,
+    /// The text of the response, as shown on the website.
+    pub message: String,
+    /// How long to wait before the next answer can be submitted, if the response says so.
+    pub wait: Option<Duration>,
+}
+
+/// Reads how long to wait before submitting again from the text of a response. Understands
+/// both the cooldown of rate-limited submissions ("You have 1m 5s left to wait.") and the
+/// lockout after wrong answers ("Please wait one minute before trying again.").
+#[must_use]
+pub fn parse_wait(text: &str) -> Option<Duration> {
+    let lower = text.to_ascii_lowercase();
+
+    if let Some(end) = lower.find(" left to wait") {
+        let start = lower[..end].rfind("you have ")? + "you have ".len();
+        return parse_duration(&lower[start..end]);
+    }
+
+    let start = lower.find("please wait ")? + "please wait ".len();
+    let mut words = lower[start..].split_whitespace();
+    let amount = parse_number(words.next()?)?;
+    let seconds = match words.next()?.trim_end_matches(['.', ',']) {
+        "second" | "seconds" => 1,
+        "minute" | "minutes" => 60,
+        "hour" | "hours" => 60 * 60,
+        _ => return None,
+    };
+    Some(Duration::from_secs(amount * seconds))
+}
+
+/// Parses durations like `45s` or `1m 5s`.
+fn parse_duration(s: &str) -> Option<Duration> {
+    let mut seconds = 0;
+    for part in s.split_whitespace() {
+        let (value, factor) = if let Some(value) = part.strip_suffix('h') {
+            (value, 60 * 60)
+        } else if let Some(value) = part.strip_suffix('m') {
+            (value, 60)
+        } else {
+            (part.strip_suffix('s')?, 1)
+        };
+        seconds += value.parse::<u64>().ok()? * factor;
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+fn parse_number(word: &str) -> Option<u64> {
+    const WORDS: [&str; 10] = [
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten",
+    ];
+
+    word.parse()
+        .ok()
+        .or_else(|| WORDS.iter().position(|w| *w == word).map(|i| i as u64 + 1))
+}
+
+/* -------------------------------------------------------------------------- */
+
+pub struct AocClient {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+}
+
+impl AocClient {
+    #[must_use]
+    pub fn new(base_url: &str, session: &str) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .timeout(Duration::from_secs(30))
+            .build();
+
+        Self {
+            agent,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+        }
+    }
+
+    /// Sets up a client from the environment, see the module documentation.
+    pub fn from_env() -> Result<Self, Error> {
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+        Ok(Self::new(&base_url, &read_session()?))
+    }
+
+    fn puzzle_url(&self, puzzle: Puzzle) -> String {
+        format!(
+            "{}/{}/day/{}",
+            self.base_url,
+            puzzle.year,
+            puzzle.day.into_inner()
+        )
+    }
+
+    fn get(&self, url: &str) -> Result<String, Error> {
+        let response = self
+            .agent
+            .get(url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call()?;
+        Ok(response.into_string()?)
+    }
+
+    /// Fetches the input of a puzzle.
+    pub fn get_input(&self, puzzle: Puzzle) -> Result<String, Error> {
+        self.get(&format!("{}/input", self.puzzle_url(puzzle)))
+    }
+
+    /// Fetches the page of a puzzle and converts its description to markdown.
+    pub fn get_puzzle(&self, puzzle: Puzzle) -> Result<String, Error> {
+        Ok(html::to_markdown(&self.get(&self.puzzle_url(puzzle))?))
+    }
+
+    /// Fetches the JSON of a private leaderboard the session has access to.
+    pub fn get_leaderboard(&self, year: Year, id: u64) -> Result<String, Error> {
+        self.get(&format!(
+            "{}/{year}/leaderboard/private/view/{id}.json",
+            self.base_url
+        ))
+    }
+
+    /// Submits an answer for a part of a puzzle.
+    pub fn submit(&self, puzzle: Puzzle, part: u8, answer: &str) -> Result<Submission, Error> {
+        let response = self
+            .agent
+            .post(&format!("{}/answer", self.puzzle_url(puzzle)))
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])?;
+
+        let html = response.into_string()?;
+        let message = html::article_text(&html);
+        Ok(Submission {
+            outcome: Outcome::parse(&html),
+            wait: parse_wait(&message),
+            message,
+        })
+    }
+}
+
+fn session_file_path() -> Option<PathBuf> {
+    if let Ok(path) = env::var("AOC_SESSION_FILE") {
+        return Some(path.into());
+    }
+
+    env::var("HOME")
+        .or_else(|_| env::var("USERPROFILE"))
+        .ok()
+        .map(|home| PathBuf::from(home).join(".adventofcode.session"))
+}
+
+fn read_session() -> Result<String, Error> {
+    let session = match env::var("AOC_SESSION") {
+        Ok(session) => session,
+        Err(_) => session_file_path()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .ok_or(Error::MissingSession)?,
+    };
+
+    if session.trim().is_empty() {
+        return Err(Error::MissingSession);
+    }
+
+    Ok(session)
+}
+
+/// Reads the default year from `AOC_YEAR`, which `.cargo/config.toml` sets for every cargo
+/// command. Errors if the variable is missing or not a valid year.
+pub fn get_year() -> Result<Year, Error> {
+    let year = env::var("AOC_YEAR").map_err(|_| Error::MissingYear)?;
+    year.trim().parse().map_err(|_| Error::InvalidYear(year))
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{
+        io::{Read, Write},
+        net::TcpListener,
+        thread::{self, JoinHandle},
+        time::Duration,
+    };
+
+    use super::{parse_wait, AocClient, Outcome};
+    use crate::{day, year, Puzzle};
+
+    fn page(text: &str) -> String {
+        format!("<html><body><main><article><p>{text}</p></article></main></body></html>")
+    }
+
+    /// Serves a single request with the given body and returns the raw request it received.
+    fn serve_once(body: String) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = vec![];
+            let mut buf = [0; 1024];
+
+            // read the head, then as much of the body as announced.
+            loop {
+                let n = stream.read(&mut buf).unwrap();
+                request.extend_from_slice(&buf[..n]);
+                let text = String::from_utf8_lossy(&request).to_string();
+                if let Some(head_end) = text.find("\r\n\r\n") {
+                    let length = text
+                        .lines()
+                        .find_map(|l| {
+                            l.to_ascii_lowercase()
+                                .strip_prefix("content-length: ")
+                                .map(|v| v.trim().parse::<usize>().unwrap())
+                        })
+                        .unwrap_or(0);
+                    if request.len() >= head_end + 4 + length {
+                        break;
+                    }
+                }
+            }
+
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+            String::from_utf8_lossy(&request).to_string()
+        });
+
+        (base_url, handle)
+    }
+
+    #[test]
+    fn parses_outcomes() {
+        let cases = [
+            (
+                "That's the right answer! You are one gold star closer.",
+                Outcome::Correct,
+            ),
+            (
+                "That's not the right answer. If you're stuck, ...",
+                Outcome::Wrong,
+            ),
+            (
+                "That's not the right answer; your answer is too high.",
+                Outcome::TooHigh,
+            ),
+            (
+                "That's not the right answer; your answer is too low.",
+                Outcome::TooLow,
+            ),
+            (
+                "You gave an answer too recently; you have 45s left to wait.",
+                Outcome::RateLimited,
+            ),
+            (
+                "You don't seem to be solving the right level. Did you already complete it?",
+                Outcome::AlreadySolved,
+            ),
+        ];
+
+        for (text, outcome) in cases {
+            assert_eq!(Outcome::parse(&page(text)), outcome);
+        }
+
+        assert_eq!(
+            Outcome::parse(&page("Something new.")),
+            Outcome::Unknown("Something new.".into())
+        );
+    }
+
+    #[test]
+    fn parses_wait_times() {
+        let cases = [
+            (
+                "You gave an answer too recently; you have to wait after submitting an answer \
+                 before trying again. You have 45s left to wait.",
+                Some(45),
+            ),
+            (
+                "You gave an answer too recently; you have to wait after submitting an answer \
+                 before trying again. You have 1m 5s left to wait.",
+                Some(65),
+            ),
+            (
+                "That's not the right answer. Please wait one minute before trying again.",
+                Some(60),
+            ),
+            (
+                "That's not the right answer; your answer is too low. please wait 5 minutes \
+                 before trying again.",
+                Some(300),
+            ),
+            ("That's the right answer!", None),
+        ];
+
+        for (text, seconds) in cases {
+            assert_eq!(parse_wait(text), seconds.map(Duration::from_secs));
+        }
+    }
+
+    #[test]
+    fn submits_answers() {
+        let (base_url, server) = serve_once(page("That's the right answer!"));
+        let client = AocClient::new(&base_url, "abc\n");
+
+        let submission = client
+            .submit(Puzzle::new(year!(2023), day!(1)), 2, "42")
+            .unwrap();
+        assert_eq!(submission.outcome, Outcome::Correct);
+        assert_eq!(submission.message, "That's the right answer!");
+
+        let request = server.join().unwrap();
+        assert_eq!(
+            request.starts_with("POST /2023/day/1/answer HTTP/1.1"),
+            true
+        );
+        assert_eq!(request.contains("session=abc\r\n"), true);
+        assert_eq!(request.ends_with("level=2&answer=42"), true);
+    }
+
+    #[test]
+    fn fetches_inputs() {
+        let (base_url, server) = serve_once("1abc2\n".into());
+        let client = AocClient::new(&base_url, "abc");
+
+        assert_eq!(
+            client
+                .get_input(Puzzle::new(year!(2022), day!(12)))
+                .unwrap(),
+            "1abc2\n"
+        );
+        assert_eq!(
+            server
+                .join()
+                .unwrap()
+                .starts_with("GET /2022/day/12/input HTTP/1.1"),
+            true
+        );
+    }
+
+    #[test]
+    fn fetches_leaderboards() {
+        let (base_url, server) = serve_once("{}".into());
+        let client = AocClient::new(&base_url, "abc");
+
+        assert_eq!(client.get_leaderboard(year!(2023), 123_456).unwrap(), "{}");
+        assert_eq!(
+            server
+                .join()
+                .unwrap()
+                .starts_with("GET /2023/leaderboard/private/view/123456.json HTTP/1.1"),
+            true
+        );
+    }
+}
@@ -0,0 +1,374 @@
+/// Module that keeps a history of benchmark runs of a year in `data/<year>/bench_history.jsonl`.
+/// Every `cargo time` run appends one JSON line keyed by git commit, timestamp and toolchain,
+/// so that timings of different commits can be compared with `cargo bench-diff`.
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::PathBuf,
+    process::Command,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use tinyjson::JsonValue;
+
+use crate::{
+    template::{
+        get_year_path,
+        records::{PartRecord, Status},
+    },
+    Day, Year,
+};
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "could not parse benchmark history: {e}"),
+            Error::IO(e) => write!(f, "could not access benchmark history: {e}"),
+        }
+    }
+}
+
+/// The median timing of a single part in a benchmark run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartTiming {
+    pub day: Day,
+    pub part: u8,
+    pub median: Duration,
+    pub std_dev: Duration,
+}
+
+/// A single `cargo time` run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    /// The commit that was checked out, [`None`] outside of a git repository.
+    pub commit: Option<String>,
+    /// Whether the working tree had uncommitted changes.
+    pub dirty: bool,
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    pub toolchain: String,
+    pub timings: Vec<PartTiming>,
+}
+
+impl Entry {
+    /// Creates an entry for the current state of the repository from the records of a timed run.
+    #[must_use]
+    pub fn new(records: &[PartRecord]) -> Self {
+        Self {
+            commit: git(&["rev-parse", "HEAD"]),
+            // `cargo time` rewrites the readme and the history itself, they don't change timings.
+            dirty: git(&[
+                "status",
+                "--porcelain",
+                "--",
+                ".",
+                ":!README.md",
+                ":!data/*/bench_history.jsonl",
+            ])
+            .is_some_and(|s| !s.is_empty()),
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_secs()),
+            toolchain: env!("AOC_RUSTC_VERSION").to_string(),
+            timings: records
+                .iter()
+                .filter(|record| record.status == Status::Solved)
+                .map(|record| PartTiming {
+                    day: record.day,
+                    part: record.part,
+                    median: record.stats.median,
+                    std_dev: record.stats.std_dev,
+                })
+                .collect(),
+        }
+    }
+
+    /// A short label for the entry, e.g. `1df1881 (dirty)`.
+    #[must_use]
+    pub fn label(&self) -> String {
+        let commit = self
+            .commit
+            .as_deref()
+            .map_or("unknown commit", |c| &c[..c.len().min(7)]);
+        if self.dirty {
+            format!("{commit} (dirty)")
+        } else {
+            commit.to_string()
+        }
+    }
+
+    fn to_json(&self) -> JsonValue {
+        let mut object: HashMap<String, JsonValue> = HashMap::new();
+        object.insert(
+            "commit".into(),
+            self.commit
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+        object.insert("dirty".into(), self.dirty.into());
+        #[allow(clippy::cast_precision_loss)]
+        object.insert("timestamp".into(), (self.timestamp as f64).into());
+        object.insert("toolchain".into(), self.toolchain.clone().into());
+        object.insert(
+            "timings".into(),
+            JsonValue::Array(self.timings.iter().map(timing_to_json).collect()),
+        );
+        object.into()
+    }
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn from_json(value: &JsonValue) -> Option<Self> {
+        let object: &HashMap<_, _> = value.get()?;
+
+        let commit = match object.get("commit")? {
+            JsonValue::Null => None,
+            JsonValue::String(commit) => Some(commit.clone()),
+            _ => return None,
+        };
+
+        let timings: &Vec<_> = object.get("timings")?.get()?;
+
+        Some(Self {
+            commit,
+            dirty: *object.get("dirty")?.get()?,
+            timestamp: *object.get("timestamp")?.get::<f64>()? as u64,
+            toolchain: object.get("toolchain")?.get::<String>()?.clone(),
+            timings: timings
+                .iter()
+                .map(timing_from_json)
+                .collect::<Option<_>>()?,
+        })
+    }
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn timing_to_json(timing: &PartTiming) -> JsonValue {
+    let mut object: HashMap<String, JsonValue> = HashMap::new();
+    object.insert("day".into(), f64::from(timing.day.into_inner()).into());
+    object.insert("part".into(), f64::from(timing.part).into());
+    object.insert("median_ns".into(), (timing.median.as_nanos() as f64).into());
+    object.insert(
+        "std_dev_ns".into(),
+        (timing.std_dev.as_nanos() as f64).into(),
+    );
+    object.into()
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn timing_from_json(value: &JsonValue) -> Option<PartTiming> {
+    let object: &HashMap<_, _> = value.get()?;
+    let number = |key: &str| -> Option<f64> { object.get(key)?.get::<f64>().copied() };
+
+    Some(PartTiming {
+        day: Day::new(number("day")? as u8)?,
+        part: number("part")? as u8,
+        median: Duration::from_nanos(number("median_ns")? as u64),
+        std_dev: Duration::from_nanos(number("std_dev_ns")? as u64),
+    })
+}
+
+/// Runs a git command and returns its trimmed output, or [`None`] if it failed.
+fn git(args: &[&str]) -> Option<String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Resolves a git revision, e.g. `HEAD~1` or a branch name, to a full commit hash.
+#[must_use]
+pub fn resolve_rev(rev: &str) -> Option<String> {
+    git(&[
+        "rev-parse",
+        "--verify",
+        "--quiet",
+        &format!("{rev}^{{commit}}"),
+    ])
+}
+
+#[must_use]
+pub fn get_path(year: Year) -> PathBuf {
+    get_year_path(year).join("bench_history.jsonl")
+}
+
+pub fn append(year: Year, entry: &Entry) -> Result<(), Error> {
+    let line = entry
+        .to_json()
+        .stringify()
+        .map_err(|e| Error::Parser(e.to_string()))?;
+
+    let path = get_path(year);
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{line}")?;
+    Ok(())
+}
+
+/// Reads all recorded runs of a year, oldest first.
+pub fn read(year: Year) -> Result<Vec<Entry>, Error> {
+    let path = get_path(year);
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+
+    parse_history(&fs::read_to_string(path)?)
+}
+
+fn parse_history(s: &str) -> Result<Vec<Entry>, Error> {
+    s.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            line.parse()
+                .ok()
+                .as_ref()
+                .and_then(Entry::from_json)
+                .ok_or_else(|| Error::Parser(format!("malformed entry on line {}", i + 1)))
+        })
+        .collect()
+}
+
+/// The change in median timing of a part between two runs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Delta {
+    pub day: Day,
+    pub part: u8,
+    pub base: Option<Duration>,
+    pub current: Option<Duration>,
+}
+
+impl Delta {
+    /// The relative change in percent, [`None`] if the part is missing from either run.
+    #[must_use]
+    pub fn change_percent(&self) -> Option<f64> {
+        let base = self.base?.as_secs_f64();
+        let current = self.current?.as_secs_f64();
+        if base == 0_f64 {
+            return None;
+        }
+        Some((current - base) / base * 100_f64)
+    }
+
+    /// Whether the part got slower by more than `threshold_percent`.
+    #[must_use]
+    pub fn is_regression(&self, threshold_percent: f64) -> bool {
+        self.change_percent()
+            .is_some_and(|change| change > threshold_percent)
+    }
+}
+
+/// Compares every part that appears in either run.
+#[must_use]
+pub fn diff(base: &Entry, current: &Entry) -> Vec<Delta> {
+    let find = |entry: &Entry, day: Day, part: u8| {
+        entry
+            .timings
+            .iter()
+            .find(|t| t.day == day && t.part == part)
+            .map(|t| t.median)
+    };
+
+    let mut keys: Vec<(Day, u8)> = base
+        .timings
+        .iter()
+        .chain(current.timings.iter())
+        .map(|t| (t.day, t.part))
+        .collect();
+    keys.sort_unstable();
+    keys.dedup();
+
+    keys.into_iter()
+        .map(|(day, part)| Delta {
+            day,
+            part,
+            base: find(base, day, part),
+            current: find(current, day, part),
+        })
+        .collect()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{diff, parse_history, Entry, PartTiming};
+    use crate::day;
+
+    fn timing(day: u8, part: u8, median_micros: u64) -> PartTiming {
+        PartTiming {
+            day: crate::Day::new(day).unwrap(),
+            part,
+            median: Duration::from_micros(median_micros),
+            std_dev: Duration::from_micros(1),
+        }
+    }
+
+    fn get_mock_entry(timings: Vec<PartTiming>) -> Entry {
+        Entry {
+            commit: Some("1df1881f00".into()),
+            dirty: false,
+            timestamp: 1_701_388_800,
+            toolchain: "rustc 1.76.0-nightly".into(),
+            timings,
+        }
+    }
+
+    #[test]
+    fn roundtrips_entries() {
+        let entry = get_mock_entry(vec![timing(1, 1, 10), timing(1, 2, 20)]);
+        let line = entry.to_json().stringify().unwrap();
+        let history = parse_history(&format!("{line}\n\n{line}\n")).unwrap();
+        assert_eq!(history, vec![entry.clone(), entry]);
+    }
+
+    #[test]
+    fn errors_on_malformed_entries() {
+        assert!(parse_history("{\"commit\": null}").is_err());
+    }
+
+    #[test]
+    fn diffs_entries() {
+        let base = get_mock_entry(vec![timing(1, 1, 100), timing(1, 2, 100)]);
+        let current = get_mock_entry(vec![timing(1, 1, 150), timing(2, 1, 10)]);
+        let deltas = diff(&base, &current);
+
+        assert_eq!(deltas.len(), 3);
+        assert_eq!(deltas[0].day, day!(1));
+        assert_eq!(deltas[0].change_percent().map(f64::round), Some(50_f64));
+        assert_eq!(deltas[0].is_regression(10_f64), true);
+        assert_eq!(deltas[0].is_regression(60_f64), false);
+        assert_eq!(deltas[1].current, None);
+        assert_eq!(deltas[1].is_regression(10_f64), false);
+        assert_eq!(deltas[2].base, None);
+    }
+
+    #[test]
+    fn labels_entries() {
+        let mut entry = get_mock_entry(vec![]);
+        assert_eq!(entry.label(), "1df1881");
+        entry.dirty = true;
+        assert_eq!(entry.label(), "1df1881 (dirty)");
+    }
+}
@@ -1,257 +1,572 @@
-use std::io;
+use std::{
+    collections::BTreeMap,
+    env,
+    io::{self, BufRead, BufReader, Read},
+    process::{self, Command, ExitStatus, Stdio},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc::{self, RecvTimeoutError, Sender},
+    },
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
 
 use crate::{
     all_days,
     template::{
+        bench_history, inputs,
         readme_benchmarks::{self, Timings},
+        records::{OutputFormat, PartRecord, Status, PARSE_STEP},
+        registry::Registry,
+        runner::{self, RunOptions},
         ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
     },
-    Day,
+    Day, Puzzle, Year,
 };
 
-pub fn handle(is_release: bool, is_timed: bool) {
-    let mut timings: Vec<Timings> = vec![];
+/// How running a single day went.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DayStatus {
+    Ok,
+    /// A part (or the parse step) panicked with a message.
+    Panicked {
+        part: u8,
+        message: String,
+    },
+    /// A part returned an error with a message.
+    Errored {
+        part: u8,
+        message: String,
+    },
+    /// The day did not finish within the timeout and was killed.
+    TimedOut(Duration),
+    /// The day exited without reporting results, e.g. because its input could not be read.
+    Failed(String),
+    NotScaffolded,
+}
 
-    all_days().for_each(|day| {
-        if day > 1 {
-            println!();
+impl DayStatus {
+    #[must_use]
+    pub fn is_failure(&self) -> bool {
+        !matches!(self, Self::Ok | Self::NotScaffolded)
+    }
+
+    fn describe(&self) -> String {
+        match self {
+            Self::Ok => "ok".into(),
+            Self::Panicked { part, message } if *part == PARSE_STEP => {
+                format!("panicked in parse: {message}")
+            }
+            Self::Panicked { part, message } => format!("panicked in part {part}: {message}"),
+            Self::Errored { part, message } => format!("error in part {part}: {message}"),
+            Self::TimedOut(timeout) => format!("timed out after {timeout:?}"),
+            Self::Failed(reason) => format!("failed: {reason}"),
+            Self::NotScaffolded => "not scaffolded".into(),
         }
+    }
+}
 
-        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-        println!("------");
+/// What a day printed while it ran.
+enum Output {
+    Record(PartRecord),
+    /// Any other output of the solution, e.g. debug prints.
+    Stdout(String),
+    Stderr(String),
+}
 
-        let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+impl Output {
+    fn print(&self, format: OutputFormat) {
+        match self {
+            Self::Record(record) => runner::print_record(record, format),
+            Self::Stdout(line) => println!("{line}"),
+            Self::Stderr(line) => eprintln!("{line}"),
+        }
+    }
+}
 
-        if output.is_empty() {
-            println!("Not solved.");
-        } else {
-            let val = child_commands::parse_exec_time(&output, day);
-            timings.push(val);
+struct DayRun {
+    records: Vec<PartRecord>,
+    status: DayStatus,
+}
+
+/// Runs every scaffolded solution of `year`. Each day runs in its own child process, so a
+/// panicking or hanging day can't take down the remaining days. Days that take longer than
+/// `timeout` are killed. With more than one job, days run in parallel and their output is printed
+/// in day order. Exits with a non-zero status if any day failed.
+pub fn handle(
+    registry: &Registry,
+    year: Year,
+    options: &RunOptions,
+    timeout: Option<Duration>,
+    jobs: usize,
+) {
+    let is_human = options.format == OutputFormat::Human;
+    let mut timings: Vec<Timings> = vec![];
+    let mut all_records: Vec<PartRecord> = vec![];
+    let mut statuses: Vec<(Day, DayStatus)> = vec![];
+
+    let mut finish_day = |day: Day, run: DayRun| {
+        if is_human {
+            match &run.status {
+                DayStatus::NotScaffolded => println!("Not scaffolded."),
+                DayStatus::TimedOut(timeout) => println!("✖ timed out after {timeout:?}"),
+                DayStatus::Failed(reason) => println!("✖ {reason}"),
+                _ => {}
+            }
         }
-    });
 
-    if is_timed {
+        if run.status == DayStatus::Ok {
+            timings.push(collect_timings(&run.records, Puzzle::new(year, day)));
+        }
+        all_records.extend(run.records);
+        statuses.push((day, run.status));
+    };
+
+    if jobs > 1 && options.time {
+        eprintln!("Running days one at a time, parallel runs would distort the benchmarks.");
+    }
+
+    if jobs > 1 && !options.time {
+        run_parallel(
+            registry,
+            year,
+            options,
+            timeout,
+            jobs,
+            |day, output, run| {
+                print_header(day, is_human);
+                output.iter().for_each(|line| line.print(options.format));
+                finish_day(day, run);
+            },
+        );
+    } else {
+        all_days().for_each(|day| {
+            print_header(day, is_human);
+            let puzzle = Puzzle::new(year, day);
+            let run = run_day(registry, puzzle, options, timeout, &mut |line| {
+                line.print(options.format);
+            });
+            finish_day(day, run);
+        });
+    }
+
+    print_summary(&statuses, is_human);
+    let failures = statuses.iter().filter(|(_, s)| s.is_failure()).count();
+
+    if options.time {
         let total_millis = timings.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64;
 
-        println!("\n{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}");
+        if is_human {
+            println!(
+                "\n{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
+            );
+        }
 
-        if is_release {
-            match readme_benchmarks::update(timings, total_millis) {
-                Ok(()) => println!("Successfully updated README with benchmarks."),
+        // only optimized builds with every day intact produce timings worth keeping.
+        if !cfg!(debug_assertions) && failures == 0 {
+            // check the tree for changes before the readme is updated.
+            let entry = bench_history::Entry::new(&all_records);
+
+            match readme_benchmarks::update(year, timings, total_millis) {
+                Ok(()) if is_human => println!("Successfully updated README with benchmarks."),
+                Ok(()) => eprintln!("Successfully updated README with benchmarks."),
                 Err(_) => {
                     eprintln!("Failed to update readme with benchmarks.");
                 }
             }
+
+            if let Err(e) = bench_history::append(year, &entry) {
+                eprintln!("Failed to record benchmark history: {e}");
+            }
         }
     }
-}
 
-#[derive(Debug)]
-pub enum Error {
-    BrokenPipe,
-    Parser(String),
-    IO(io::Error),
+    if failures > 0 {
+        process::exit(1);
+    }
 }
 
-impl From<std::io::Error> for Error {
-    fn from(e: std::io::Error) -> Self {
-        Error::IO(e)
+fn print_header(day: Day, is_human: bool) {
+    if is_human {
+        if day > 1 {
+            println!();
+        }
+
+        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+        println!("------");
     }
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
-}
+/// Runs days on `jobs` worker threads and calls `on_day` with each day's buffered output,
+/// in day order.
+fn run_parallel(
+    registry: &Registry,
+    year: Year,
+    options: &RunOptions,
+    timeout: Option<Duration>,
+    jobs: usize,
+    mut on_day: impl FnMut(Day, Vec<Output>, DayRun),
+) {
+    let days: Vec<Day> = all_days().collect();
+    let next = AtomicUsize::new(0);
+    let (tx, rx) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs {
+            let (tx, days, next) = (tx.clone(), &days, &next);
+            scope.spawn(move || loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(&day) = days.get(index) else {
+                    break;
+                };
 
-/// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
-mod child_commands {
-    use std::{
-        io::{BufRead, BufReader},
-        path::Path,
-        process::{Command, Stdio},
-        thread,
-    };
+                let mut output = vec![];
+                let puzzle = Puzzle::new(year, day);
+                let run = run_day(registry, puzzle, options, timeout, &mut |line| {
+                    output.push(line)
+                });
+                if tx.send((index, output, run)).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(tx);
 
-    use super::{get_path_for_bin, Error};
-    use crate::Day;
+        // days can finish out of order, hold on to them until all earlier days are printed.
+        let mut pending = BTreeMap::new();
+        let mut next_to_print = 0;
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
-        // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
+        for (index, output, run) in rx {
+            pending.insert(index, (output, run));
+            while let Some((output, run)) = pending.remove(&next_to_print) {
+                on_day(days[next_to_print], output, run);
+                next_to_print += 1;
+            }
         }
+    });
+}
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+fn run_day(
+    registry: &Registry,
+    puzzle: Puzzle,
+    options: &RunOptions,
+    timeout: Option<Duration>,
+    on_output: &mut dyn FnMut(Output),
+) -> DayRun {
+    if registry.get(puzzle).is_none() {
+        return DayRun {
+            records: vec![],
+            status: DayStatus::NotScaffolded,
+        };
+    }
 
-        if is_release {
-            args.push("--release");
-        }
+    // catch inputs that were never downloaded here, rather than as a panic in the solution.
+    if let Err(e) = inputs::read(puzzle) {
+        return DayRun {
+            records: vec![],
+            status: DayStatus::Failed(e.to_string()),
+        };
+    }
 
-        if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
-        }
+    run_isolated(puzzle, options, timeout, on_output).unwrap_or_else(|e| DayRun {
+        records: vec![],
+        status: DayStatus::Failed(format!("could not start solution: {e}")),
+    })
+}
 
-        // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+/// Runs a day through `solve --format json` in a child process of the current executable and
+/// passes its output to `on_output` as it arrives.
+fn run_isolated(
+    puzzle: Puzzle,
+    options: &RunOptions,
+    timeout: Option<Duration>,
+    on_output: &mut dyn FnMut(Output),
+) -> io::Result<DayRun> {
+    let mut cmd = Command::new(env::current_exe()?);
+    cmd.args(["solve", &puzzle.day.to_string(), "--format", "json"])
+        .args(["--year", &puzzle.year.to_string()])
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+
+    if options.time {
+        cmd.arg("--time");
+    }
+    if options.reject_outliers {
+        cmd.arg("--reject-outliers");
+    }
 
-        let mut cmd = Command::new("cargo")
-            .args(&args)
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()?;
+    let mut child = cmd.spawn()?;
+
+    // read on separate threads, so the timeout also applies while the child is silent.
+    let (tx, rx) = mpsc::channel();
+    let stdout = child.stdout.take().unwrap();
+    let stderr = child.stderr.take().unwrap();
+    let readers = [
+        forward_lines(stdout, tx.clone(), Output::Stdout),
+        forward_lines(stderr, tx, Output::Stderr),
+    ];
+
+    let deadline = timeout.map(|timeout| Instant::now() + timeout);
+    let mut records = vec![];
+    let mut timed_out = false;
+
+    loop {
+        let output = match deadline {
+            Some(deadline) => {
+                match rx.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+                    Ok(output) => output,
+                    Err(RecvTimeoutError::Timeout) => {
+                        timed_out = true;
+                        child.kill()?;
+                        break;
+                    }
+                    Err(RecvTimeoutError::Disconnected) => break,
+                }
+            }
+            None => match rx.recv() {
+                Ok(output) => output,
+                Err(_) => break,
+            },
+        };
 
-        let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
-        let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
+        let output = match output {
+            Output::Stdout(line) => match PartRecord::from_json_line(&line) {
+                Some(record) => {
+                    records.push(record.clone());
+                    Output::Record(record)
+                }
+                None => Output::Stdout(line),
+            },
+            output => output,
+        };
 
-        let mut output = vec![];
+        on_output(output);
+    }
 
-        let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
-            });
-        });
+    let exit_status = child.wait()?;
+    for reader in readers {
+        let _ = reader.join();
+    }
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
-        }
+    let status = match timeout {
+        Some(timeout) if timed_out => DayStatus::TimedOut(timeout),
+        _ => status_of(&records, exit_status),
+    };
+
+    Ok(DayRun { records, status })
+}
 
-        thread.join().unwrap();
-        cmd.wait()?;
+fn forward_lines(
+    source: impl Read + Send + 'static,
+    tx: Sender<Output>,
+    wrap: fn(String) -> Output,
+) -> JoinHandle<()> {
+    thread::spawn(move || {
+        for line in BufReader::new(source).lines().map_while(Result::ok) {
+            if tx.send(wrap(line)).is_err() {
+                break;
+            }
+        }
+    })
+}
 
-        Ok(output)
+fn status_of(records: &[PartRecord], exit_status: ExitStatus) -> DayStatus {
+    if let Some(record) = records.iter().find(|r| r.status == Status::Panicked) {
+        return DayStatus::Panicked {
+            part: record.part,
+            message: record.error.clone().unwrap_or_default(),
+        };
     }
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timings {
-        let mut timings = super::Timings {
-            day,
-            part_1: None,
-            part_2: None,
-            total_nanos: 0_f64,
+    if let Some(record) = records.iter().find(|r| r.status == Status::Errored) {
+        return DayStatus::Errored {
+            part: record.part,
+            message: record.error.clone().unwrap_or_default(),
         };
+    }
 
-        output
+    if !exit_status.success() {
+        return DayStatus::Failed(format!("exited with {exit_status}"));
+    }
+
+    DayStatus::Ok
+}
+
+fn print_summary(statuses: &[(Day, DayStatus)], is_human: bool) {
+    let mut lines = vec![
+        String::new(),
+        format!("{ANSI_BOLD}Summary{ANSI_RESET}"),
+        "-------".to_string(),
+        "| Day | Status |".to_string(),
+        "| :---: | :--- |".to_string(),
+    ];
+
+    lines.extend(
+        statuses
             .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
-                }
+            .filter(|(_, status)| *status != DayStatus::NotScaffolded)
+            .map(|(day, status)| format!("| {day} | {} |", status.describe())),
+    );
+
+    let failures = statuses.iter().filter(|(_, s)| s.is_failure()).count();
+    lines.push(String::new());
+    lines.push(if failures > 0 {
+        format!("{ANSI_BOLD}{failures} day(s) failed.{ANSI_RESET}")
+    } else {
+        "All scaffolded days ran successfully.".to_string()
+    });
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
+    // keep stdout a clean stream of records in json mode.
+    for line in lines {
+        if is_human {
+            println!("{line}");
+        } else {
+            eprintln!("{line}");
+        }
+    }
+}
+
+fn collect_timings(records: &[PartRecord], puzzle: Puzzle) -> Timings {
+    let mut timings = Timings {
+        puzzle,
+        parse: None,
+        part_1: None,
+        part_2: None,
+        parse_allocs: None,
+        part_1_allocs: None,
+        part_2_allocs: None,
+        total_nanos: 0_f64,
+    };
 
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
+    records
+        .iter()
+        .filter(|record| record.status == Status::Solved)
+        .for_each(|record| {
+            match record.part {
+                PARSE_STEP => {
+                    timings.parse = Some(record.stats);
+                    timings.parse_allocs = record.allocs;
+                }
+                1 => {
+                    timings.part_1 = Some(record.stats);
+                    timings.part_1_allocs = record.allocs;
                 }
+                2 => {
+                    timings.part_2 = Some(record.stats);
+                    timings.part_2_allocs = record.allocs;
+                }
+                _ => {}
+            }
 
-                timings.total_nanos += nanos;
-            });
+            #[allow(clippy::cast_precision_loss)]
+            let nanos = record.stats.median.as_nanos() as f64;
+            timings.total_nanos += nanos;
+        });
 
-        timings
-    }
+    timings
+}
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
-    }
+/// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
+#[cfg(feature = "test_lib")]
+macro_rules! assert_approx_eq {
+    ($a:expr, $b:expr) => {{
+        let (a, b) = (&$a, &$b);
+        assert!(
+            (*a - *b).abs() < 1.0e-6,
+            "{} is not approximately equal to {}",
+            *a,
+            *b
+        );
+    }};
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{collect_timings, DayStatus};
+    use crate::{
+        day,
+        template::{
+            records::{PartRecord, Status, PARSE_STEP},
+            stats::Stats,
+        },
+        year, Puzzle,
+    };
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .last()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
+    fn record(part: u8, answer: Option<&str>, median: Duration) -> PartRecord {
+        PartRecord {
+            stats: Stats::single(median),
+            ..PartRecord::mock(part, answer)
+        }
     }
 
-    /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-    #[cfg(feature = "test_lib")]
-    macro_rules! assert_approx_eq {
-        ($a:expr, $b:expr) => {{
-            let (a, b) = (&$a, &$b);
-            assert!(
-                (*a - *b).abs() < 1.0e-6,
-                "{} is not approximately equal to {}",
-                *a,
-                *b
-            );
-        }};
+    #[test]
+    fn test_well_formed() {
+        let res = collect_timings(
+            &[
+                record(1, Some("0"), Duration::from_nanos(74)),
+                record(2, Some("10"), Duration::from_micros(74130)),
+            ],
+            Puzzle::new(year!(2023), day!(1)),
+        );
+        assert_approx_eq!(res.total_nanos, 74130074_f64);
+        assert_eq!(res.part_1.unwrap().median, Duration::from_nanos(74));
+        assert_eq!(res.part_2.unwrap().median, Duration::from_micros(74130));
     }
 
-    #[cfg(feature = "test_lib")]
-    mod tests {
-        use super::parse_exec_time;
-        use crate::day;
-
-        #[test]
-        fn test_well_formed() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
-        }
+    #[test]
+    fn test_parse_step() {
+        let res = collect_timings(
+            &[
+                PartRecord {
+                    status: Status::Solved,
+                    ..record(PARSE_STEP, None, Duration::from_micros(10))
+                },
+                record(1, Some("0"), Duration::from_micros(20)),
+                record(2, Some("10"), Duration::from_micros(30)),
+            ],
+            Puzzle::new(year!(2023), day!(1)),
+        );
+        assert_approx_eq!(res.total_nanos, 60000_f64);
+        assert_eq!(res.parse.unwrap().median, Duration::from_micros(10));
+        assert_eq!(res.part_1.unwrap().median, Duration::from_micros(20));
+    }
 
-        #[test]
-        fn test_patterns_in_input() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
-        }
+    #[test]
+    fn test_missing_parts() {
+        let res = collect_timings(
+            &[
+                record(1, None, Duration::from_nanos(74)),
+                record(2, None, Duration::from_nanos(74)),
+            ],
+            Puzzle::new(year!(2023), day!(1)),
+        );
+        assert_approx_eq!(res.total_nanos, 0_f64);
+        assert_eq!(res.part_1.is_none(), true);
+        assert_eq!(res.part_2.is_none(), true);
+    }
 
-        #[test]
-        fn test_missing_parts() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 0_f64);
-            assert_eq!(res.part_1.is_none(), true);
-            assert_eq!(res.part_2.is_none(), true);
-        }
+    #[test]
+    fn test_day_status() {
+        let panicked = DayStatus::Panicked {
+            part: 2,
+            message: "not L or R".into(),
+        };
+        assert_eq!(panicked.is_failure(), true);
+        assert_eq!(panicked.describe(), "panicked in part 2: not L or R");
+        assert_eq!(
+            DayStatus::TimedOut(Duration::from_secs(5)).describe(),
+            "timed out after 5s"
+        );
+        let errored = DayStatus::Errored {
+            part: 1,
+            message: "invalid card: X".into(),
+        };
+        assert_eq!(errored.is_failure(), true);
+        assert_eq!(errored.describe(), "error in part 1: invalid card: X");
+        assert_eq!(DayStatus::Ok.is_failure(), false);
+        assert_eq!(DayStatus::NotScaffolded.is_failure(), false);
     }
 }
@@ -0,0 +1,103 @@
+use std::process;
+
+use crate::{
+    template::{
+        bench_history::{self, Entry},
+        records::PARSE_STEP,
+        ANSI_BOLD, ANSI_RESET,
+    },
+    Year,
+};
+
+/// Compares the latest recorded benchmark run of `year` against the most recent run of `rev`, or
+/// against the previous run if no revision is given. Exits with a non-zero status if any part got
+/// slower by more than `threshold` percent.
+pub fn handle(year: Year, rev: Option<&str>, threshold: f64) {
+    let history = match bench_history::read(year) {
+        Ok(history) => history,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+
+    let Some((current, previous)) = history.split_last() else {
+        eprintln!(
+            "No benchmark history recorded for {year} yet. Run \"cargo time --year {year}\" to \
+             record a run."
+        );
+        process::exit(1);
+    };
+
+    let base = match rev {
+        Some(rev) => {
+            let Some(commit) = bench_history::resolve_rev(rev) else {
+                eprintln!("Could not resolve git revision \"{rev}\".");
+                process::exit(1);
+            };
+            find_latest_for_commit(previous, &commit)
+        }
+        None => previous.last(),
+    };
+
+    let Some(base) = base else {
+        eprintln!("No earlier benchmark run found to compare against.");
+        process::exit(1);
+    };
+
+    println!(
+        "Comparing {ANSI_BOLD}{}{ANSI_RESET} ({}) against {ANSI_BOLD}{}{ANSI_RESET} ({})",
+        current.label(),
+        current.toolchain,
+        base.label(),
+        base.toolchain
+    );
+    println!();
+    println!("| Day | Part | Base | Current | Change |");
+    println!("| :---: | :---: | ---: | ---: | ---: |");
+
+    let deltas = bench_history::diff(base, current);
+    let mut regressions = 0;
+
+    for delta in &deltas {
+        let part = if delta.part == PARSE_STEP {
+            "Parse".to_string()
+        } else {
+            delta.part.to_string()
+        };
+        let format = |d: Option<std::time::Duration>| d.map_or("-".into(), |d| format!("{d:.1?}"));
+        let change = delta
+            .change_percent()
+            .map_or("-".into(), |change| format!("{change:+.1}%"));
+
+        let flag = if delta.is_regression(threshold) {
+            regressions += 1;
+            " ▲ regression"
+        } else {
+            ""
+        };
+
+        println!(
+            "| {} | {part} | {} | {} | {change}{flag} |",
+            delta.day,
+            format(delta.base),
+            format(delta.current)
+        );
+    }
+
+    println!();
+    if regressions > 0 {
+        println!(
+            "{ANSI_BOLD}{regressions} part(s) got slower by more than {threshold}%.{ANSI_RESET}"
+        );
+        process::exit(1);
+    }
+    println!("No regressions above {threshold}%.");
+}
+
+fn find_latest_for_commit<'a>(history: &'a [Entry], commit: &str) -> Option<&'a Entry> {
+    history
+        .iter()
+        .rev()
+        .find(|entry| entry.commit.as_deref() == Some(commit))
+}
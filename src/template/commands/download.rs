@@ -1,18 +1,72 @@
-use std::process;
+use std::{
+    fs,
+    path::Path,
+    process,
+    time::{Duration, SystemTime},
+};
 
-use crate::{template::aoc_cli, Day};
+use crate::{
+    template::{aoc_client::AocClient, countdown, format_wait, get_data_path, inputs},
+    Puzzle,
+};
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!(
-            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to \
-             install it."
-        );
-        process::exit(1);
+/// Downloads the input and description of a puzzle. With `wait`, a puzzle that is still locked
+/// is downloaded as soon as it unlocks.
+pub fn handle(puzzle: Puzzle, wait: bool) {
+    let client = match AocClient::from_env() {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+
+    let day = puzzle.day;
+    if let Ok(left) = day
+        .unlocks_at(puzzle.year)
+        .duration_since(SystemTime::now())
+    {
+        if !wait {
+            eprintln!(
+                "Day {day} of {} unlocks in {}. Append --wait to download it as soon as it does.",
+                puzzle.year,
+                format_wait(left.as_secs() + 1)
+            );
+            process::exit(1);
+        }
+        // give the website a moment to publish the puzzle.
+        countdown(left + Duration::from_secs(2), "downloading");
     }
 
-    if let Err(e) = aoc_cli::download(day) {
-        eprintln!("failed to call aoc-cli: {e}");
+    let input_path = get_data_path(puzzle.year, "inputs", &format!("{day}.txt"));
+    let puzzle_path = get_data_path(puzzle.year, "puzzles", &format!("{day}.md"));
+
+    let result = client.get_input(puzzle).and_then(|input| {
+        let description = client.get_puzzle(puzzle)?;
+        write_file(&input_path, &input)?;
+        inputs::record_checksum(puzzle, &input)?;
+        write_file(&puzzle_path, &description)?;
+        Ok(())
+    });
+
+    if let Err(e) = result {
+        eprintln!("Failed to download day {day} of {}: {e}", puzzle.year);
         process::exit(1);
-    };
+    }
+
+    println!(
+        "🎄 Successfully wrote input to \"{}\".",
+        input_path.display()
+    );
+    println!(
+        "🎄 Successfully wrote puzzle to \"{}\".",
+        puzzle_path.display()
+    );
+}
+
+fn write_file(path: &Path, contents: &str) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, contents)
 }
@@ -0,0 +1,110 @@
+use std::{fs, process};
+
+use crate::{
+    template::{
+        commands::gen_tests,
+        example_tests,
+        examples::{self, Example},
+        get_data_path, read_path,
+    },
+    Puzzle,
+};
+
+/// Writes the examples of a downloaded puzzle description to numbered example files
+/// (`NN-0.txt`, `NN-1.txt`, ...) and records the answers stated near them in the day's manifest.
+/// Example files and answers that already exist are left untouched, answers are only recorded for
+/// files that hold the extracted example.
+pub fn handle(puzzle: Puzzle) {
+    let (year, day) = (puzzle.year, puzzle.day);
+    let puzzle_path = get_data_path(year, "puzzles", &format!("{day}.md"));
+
+    let markdown = match read_path(&puzzle_path) {
+        Ok(markdown) => markdown,
+        Err(e) => {
+            eprintln!("{e}. Try running \"cargo download {day} --year {year}\" first.");
+            process::exit(1);
+        }
+    };
+
+    let found = examples::extract(&markdown);
+    if found.is_empty() {
+        eprintln!("No examples found in \"{}\".", puzzle_path.display());
+        process::exit(1);
+    }
+
+    let mut manifest = match examples::read_manifest(puzzle) {
+        Ok(manifest) => manifest,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+
+    for (i, example) in found.iter().enumerate() {
+        let name = format!("{day}-{i}");
+        // the answers belong to the extracted example, not to a file made by hand.
+        if !write_example(puzzle, &format!("{name}.txt"), example) {
+            println!("  {name}: differs from the description, answers not recorded");
+            continue;
+        }
+
+        let answers = manifest.examples.entry(name.clone()).or_default();
+        for part in 1..=2 {
+            if let (None, Some(answer)) = (answers.get(part), example.answers.get(part)) {
+                answers.set(part, answer.to_string());
+            }
+        }
+
+        println!(
+            "  {name}: part one {}, part two {}",
+            answers.get(1).unwrap_or("?"),
+            answers.get(2).unwrap_or("?")
+        );
+    }
+
+    match examples::write_manifest(puzzle, &manifest) {
+        Ok(()) => println!(
+            "🎄 Recorded expected answers in \"{}\".",
+            examples::get_manifest_path(puzzle).display()
+        ),
+        Err(e) => {
+            eprintln!("Failed to write manifest: {e}");
+            process::exit(1);
+        }
+    }
+
+    if example_tests::get_path_for_bin(puzzle).exists() {
+        if let Err(e) = gen_tests::generate(puzzle) {
+            eprintln!("Failed to generate example tests: {e}");
+        }
+    }
+}
+
+/// Writes an example file unless one with content already exists. Returns whether the file
+/// holds the extracted example.
+fn write_example(puzzle: Puzzle, file_name: &str, example: &Example) -> bool {
+    let path = get_data_path(puzzle.year, "examples", file_name);
+
+    if let Ok(existing) = fs::read_to_string(&path) {
+        if !existing.trim().is_empty() {
+            println!("Kept existing example file \"{}\".", path.display());
+            return existing == example.input;
+        }
+    }
+
+    let written = path
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|()| fs::write(&path, &example.input));
+
+    match written {
+        Ok(()) => {
+            println!("Created example file \"{}\".", path.display());
+            true
+        }
+        Err(e) => {
+            eprintln!("Failed to write example file \"{}\": {e}", path.display());
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use std::process;
+
+use crate::{
+    template::{
+        example_tests::{self, Error},
+        examples,
+    },
+    Puzzle,
+};
+
+/// Writes a test for every example file and part with an expected answer to the solution of a
+/// day, replacing the tests it generated before. Hand-written tests are left untouched.
+pub fn handle(puzzle: Puzzle) {
+    let path = example_tests::get_path_for_bin(puzzle);
+    if !path.exists() {
+        eprintln!(
+            "No solution at \"{}\". Try running \"cargo scaffold {} --year {}\" first.",
+            path.display(),
+            puzzle.day,
+            puzzle.year
+        );
+        process::exit(1);
+    }
+
+    let manifest_path = examples::get_manifest_path(puzzle);
+    if !manifest_path.exists() {
+        eprintln!(
+            "No expected answers at \"{}\". Try running \"cargo examples {} --year {}\" first.",
+            manifest_path.display(),
+            puzzle.day,
+            puzzle.year
+        );
+        process::exit(1);
+    }
+
+    if let Err(e) = generate(puzzle) {
+        eprintln!("{e}");
+        process::exit(1);
+    }
+}
+
+/// Generates the example tests of a day and reports what was generated. Days without a manifest
+/// are skipped.
+pub fn generate(puzzle: Puzzle) -> Result<(), Error> {
+    if !examples::get_manifest_path(puzzle).exists() {
+        return Ok(());
+    }
+
+    let plan = example_tests::write(puzzle)?;
+
+    for test in &plan.tests {
+        println!("  {}: expects {}", test.name(), test.expected);
+    }
+    if !plan.missing.is_empty() {
+        println!(
+            "No expected answer for {}, add it to \"{}\" to generate a test.",
+            plan.missing.join(", "),
+            examples::get_manifest_path(puzzle).display()
+        );
+    }
+    if !plan.tests.is_empty() {
+        println!(
+            "🎄 Generated {} example tests in \"{}\".",
+            plan.tests.len(),
+            example_tests::get_path_for_bin(puzzle).display()
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use std::{fs, process, time::Duration};
+
+use crate::{
+    day,
+    template::{
+        aoc_client::AocClient,
+        leaderboard::{self, Leaderboard},
+        ANSI_BOLD, ANSI_RESET,
+    },
+    Day, Year,
+};
+
+/// The website asks not to fetch a private leaderboard more often than every 15 minutes.
+const REFRESH_INTERVAL: Duration = Duration::from_secs(15 * 60);
+
+/// Shows a private leaderboard. It is fetched unless the cached copy is recent enough or
+/// `cached` is set, and falls back to the cached copy if fetching fails.
+pub fn handle(year: Year, id: u64, day: Option<Day>, cached: bool) {
+    let path = leaderboard::get_path(year, id);
+    let age = fs::metadata(&path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+        .and_then(|modified| modified.elapsed().ok());
+
+    if cached || age.is_some_and(|age| age < REFRESH_INTERVAL) {
+        if let Some(age) = age {
+            println!(
+                "Using the leaderboard cached {} minute(s) ago.",
+                age.as_secs() / 60
+            );
+        }
+    } else if let Err(e) = fetch(year, id) {
+        if age.is_none() {
+            eprintln!("Failed to fetch leaderboard {id} of {year}: {e}");
+            process::exit(1);
+        }
+        eprintln!("Failed to fetch leaderboard {id} of {year}, using the cached one: {e}");
+    }
+
+    let current = match leaderboard::read(&path) {
+        Ok(Some(current)) => current,
+        Ok(None) => {
+            eprintln!("No cached leaderboard {id} for {year}, run without --cached to fetch it.");
+            process::exit(1);
+        }
+        Err(e) => {
+            eprintln!("Failed to read \"{}\": {e}", path.display());
+            process::exit(1);
+        }
+    };
+
+    let previous_path = leaderboard::get_previous_path(year, id);
+    let previous = leaderboard::read(&previous_path).unwrap_or_else(|e| {
+        eprintln!("Ignoring \"{}\": {e}", previous_path.display());
+        None
+    });
+
+    println!("{ANSI_BOLD}Private leaderboard {id} ({year}){ANSI_RESET}");
+    println!();
+    let day = day.or(current.last_day()).unwrap_or(day!(1));
+    for line in leaderboard::render(&current, previous.as_ref(), day) {
+        println!("{line}");
+    }
+}
+
+fn fetch(year: Year, id: u64) -> Result<(), String> {
+    let json = AocClient::from_env()
+        .and_then(|client| client.get_leaderboard(year, id))
+        .map_err(|e| e.to_string())?;
+
+    // invalid sessions are redirected to the login page rather than getting an error.
+    Leaderboard::parse(&json)
+        .map_err(|e| format!("{e}. Is the session valid and a member of the leaderboard?"))?;
+    leaderboard::store(year, id, &json).map_err(|e| e.to_string())
+}
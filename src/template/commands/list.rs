@@ -0,0 +1,30 @@
+use crate::{
+    template::{registry::Registry, ANSI_BOLD, ANSI_RESET},
+    Year,
+};
+
+/// Lists the scaffolded days of `year`, or of every year if `year` is [`None`].
+pub fn handle(registry: &Registry, year: Option<Year>) {
+    let mut solutions = registry
+        .iter()
+        .filter(|solution| year.is_none() || year == Some(solution.puzzle().year))
+        .peekable();
+
+    if solutions.peek().is_none() {
+        println!("No days scaffolded yet.");
+        return;
+    }
+
+    let mut current_year = None;
+    for solution in solutions {
+        let puzzle = solution.puzzle();
+        if current_year != Some(puzzle.year) {
+            if current_year.is_some() {
+                println!();
+            }
+            println!("{ANSI_BOLD}{}{ANSI_RESET}", puzzle.year);
+            current_year = Some(puzzle.year);
+        }
+        println!("Day {}", puzzle.day);
+    }
+}
@@ -1,5 +1,13 @@
 pub mod all;
+pub mod bench_diff;
 pub mod download;
+pub mod examples;
+pub mod gen_tests;
+pub mod leaderboard;
+pub mod list;
 pub mod read;
 pub mod scaffold;
 pub mod solve;
+pub mod stars;
+pub mod unscaffold;
+pub mod verify;
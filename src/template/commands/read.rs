@@ -1,18 +1,58 @@
-use std::process;
+use std::{env, fs, process};
 
-use crate::{template::aoc_cli, Day};
+use crate::{
+    template::{aoc_client::AocClient, get_data_path, markdown},
+    Puzzle,
+};
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!(
-            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to \
-             install it."
-        );
-        process::exit(1);
-    }
+/// Renders the puzzle description in the terminal. The description downloaded to
+/// `data/<year>/puzzles/NN.md` is used if there is one, it is only fetched again if missing or
+/// if `refresh` is set, e.g. to pick up part two after solving part one.
+pub fn handle(puzzle: Puzzle, refresh: bool) {
+    let puzzle_path = get_data_path(puzzle.year, "puzzles", &format!("{}.md", puzzle.day));
+
+    let description = match fs::read_to_string(&puzzle_path) {
+        Ok(description) if !refresh => description,
+        _ => {
+            let description = fetch(puzzle);
+            let written = puzzle_path
+                .parent()
+                .map_or(Ok(()), fs::create_dir_all)
+                .and_then(|()| fs::write(&puzzle_path, &description));
+            if let Err(e) = written {
+                eprintln!("Failed to write \"{}\": {e}", puzzle_path.display());
+            }
+            description
+        }
+    };
+
+    print!("{}", markdown::render(&description, terminal_width()));
+}
 
-    if let Err(e) = aoc_cli::read(day) {
-        eprintln!("failed to call aoc-cli: {e}");
-        process::exit(1);
+fn fetch(puzzle: Puzzle) -> String {
+    let client = match AocClient::from_env() {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
     };
+
+    match client.get_puzzle(puzzle) {
+        Ok(description) => description,
+        Err(e) => {
+            eprintln!("Failed to read day {} of {}: {e}", puzzle.day, puzzle.year);
+            process::exit(1);
+        }
+    }
+}
+
+/// The width of the terminal as exported by the shell in `COLUMNS`, capped to keep long
+/// paragraphs readable.
+fn terminal_width() -> usize {
+    env::var("COLUMNS")
+        .ok()
+        .and_then(|columns| columns.parse().ok())
+        .unwrap_or(80)
+        .min(100)
 }
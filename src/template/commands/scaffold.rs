@@ -1,94 +1,306 @@
 use std::{
-    fs::{File, OpenOptions},
-    io::Write,
+    fs::{self, File, OpenOptions},
+    io::{ErrorKind, Write},
+    path::{Path, PathBuf},
     process,
 };
 
-use crate::Day;
+use crate::{
+    all_days,
+    template::{commands::gen_tests, example_tests, get_data_path, read_path},
+    Day, Puzzle, Year,
+};
+
+/// The template that is used unless another one is picked with `--template`.
+pub const DEFAULT_TEMPLATE: &str = "default";
 
-const MODULE_TEMPLATE: &str = r#"advent_of_code::solution!(DAY_NUMBER);
+/// The return type of the parts, unless another one is picked with `--return-type`.
+pub const DEFAULT_RETURN_TYPE: &str = "u32";
 
-pub fn part_one(input: &str) -> Option<u32> {
-    None
+fn get_templates_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("templates")
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    None
+/// The names of the templates in `templates/`.
+fn available_templates() -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(get_templates_path())
+        .map(|entries| {
+            entries
+                .filter_map(|entry| {
+                    let path = entry.ok()?.path();
+                    (path.extension()? == "rs").then_some(path.file_stem()?.to_str()?.to_string())
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    names.sort();
+    names
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+fn load_template(name: &str) -> Result<String, String> {
+    let path = get_templates_path().join(format!("{name}.rs"));
+    fs::read_to_string(&path).map_err(|e| {
+        format!(
+            "could not read template \"{}\": {e}. Available templates: {}.",
+            path.display(),
+            available_templates().join(", ")
+        )
+    })
+}
 
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
-    }
+/// Reads the title from the first heading of a puzzle description, e.g. "Day 8: Haunted
+/// Wasteland" from `## --- Day 8: Haunted Wasteland ---`.
+fn title_of(markdown: &str) -> Option<String> {
+    let heading = markdown.lines().find_map(|line| line.strip_prefix("## "))?;
+    let title = heading.trim().trim_matches('-').trim();
+    (!title.is_empty()).then(|| title.to_string())
+}
 
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
-    }
+/// Fills in the placeholders of a template: `{{year}}`, `{{day}}`, `{{title}}` and
+/// `{{return_type}}`.
+fn render_template(template: &str, puzzle: Puzzle, title: &str, return_type: &str) -> String {
+    template
+        .replace("{{year}}", &puzzle.year.to_string())
+        .replace("{{day}}", &puzzle.day.into_inner().to_string())
+        .replace("{{title}}", title)
+        .replace("{{return_type}}", return_type)
 }
-"#;
 
-fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
+fn safe_create_file(path: &Path) -> Result<File, std::io::Error> {
     OpenOptions::new().write(true).create_new(true).open(path)
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+/// Creates an empty file, along with its folder, unless it exists already. Returns whether the
+/// file was created, existing files are never truncated.
+fn create_file(path: &Path) -> Result<bool, std::io::Error> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    match safe_create_file(path) {
+        Ok(_) => Ok(true),
+        Err(e) if e.kind() == ErrorKind::AlreadyExists => Ok(false),
+        Err(e) => Err(e),
+    }
+}
+
+/// Creates the solution of a day from a template in `templates/`, along with empty input and
+/// example files. The title is taken from the puzzle description, if it was downloaded already.
+///
+/// Existing input and example files are kept. An existing solution aborts the scaffold, unless
+/// `missing_only` is set, in which case only the missing files are created.
+pub fn handle(puzzle: Puzzle, template: &str, return_type: &str, missing_only: bool) {
+    let (year, day) = (puzzle.year, puzzle.day);
+    let input_path = get_data_path(year, "inputs", &format!("{day}.txt"));
+    let example_path = get_data_path(year, "examples", &format!("{day}.txt"));
+    let module_path = example_tests::get_path_for_bin(puzzle);
+
+    if missing_only && module_path.exists() {
+        println!("Kept existing module file \"{}\"", module_path.display());
+    } else {
+        create_module(puzzle, &module_path, template, return_type);
+    }
+
+    for (kind, path) in [("input", &input_path), ("example", &example_path)] {
+        match create_file(path) {
+            Ok(true) => println!("Created empty {kind} file \"{}\"", path.display()),
+            Ok(false) => println!("Kept existing {kind} file \"{}\"", path.display()),
+            Err(e) => {
+                eprintln!("Failed to create {kind} file: {e}");
+                process::exit(1);
+            }
+        }
+    }
+
+    // examples may have been extracted before the day was scaffolded.
+    if let Err(e) = gen_tests::generate(puzzle) {
+        eprintln!("Failed to generate example tests: {e}");
+    }
+
+    println!("---");
+    println!("🎄 Type `cargo solve {day} --year {year}` to run your solution.");
 }
 
-pub fn handle(day: Day) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+fn create_module(puzzle: Puzzle, module_path: &Path, template: &str, return_type: &str) {
+    let (year, day) = (puzzle.year, puzzle.day);
+
+    let template = match load_template(template) {
+        Ok(template) => template,
+        Err(e) => {
+            eprintln!("Failed to load template: {e}");
+            process::exit(1);
+        }
+    };
+    let title = read_path(&get_data_path(year, "puzzles", &format!("{day}.md")))
+        .ok()
+        .and_then(|markdown| title_of(&markdown))
+        .unwrap_or_else(|| format!("Day {}", day.into_inner()));
 
-    let mut file = match safe_create_file(&module_path) {
+    let mut file = match safe_create_file(module_path) {
         Ok(file) => file,
+        Err(e) if e.kind() == ErrorKind::AlreadyExists => {
+            eprintln!(
+                "Module file \"{}\" already exists. Pass `--missing-only` to only create the \
+                 missing files of the day.",
+                module_path.display()
+            );
+            process::exit(1);
+        }
         Err(e) => {
             eprintln!("Failed to create module file: {e}");
             process::exit(1);
         }
     };
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("DAY_NUMBER", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
+    match file.write_all(render_template(&template, puzzle, &title, return_type).as_bytes()) {
         Ok(()) => {
-            println!("Created module file \"{}\"", &module_path);
+            println!("Created module file \"{}\"", module_path.display());
         }
         Err(e) => {
             eprintln!("Failed to write module contents: {e}");
             process::exit(1);
         }
     }
+}
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create input file: {e}");
-            process::exit(1);
+/* -------------------------------------------------------------------------- */
+
+/// Which files of a day exist. Input and example files only count if they aren't empty, as
+/// scaffolding creates them empty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Status {
+    day: Day,
+    solution: bool,
+    example: bool,
+    input: bool,
+}
+
+impl Status {
+    fn read(puzzle: Puzzle) -> Self {
+        let has_content = |folder: &str| {
+            fs::read_to_string(get_data_path(
+                puzzle.year,
+                folder,
+                &format!("{}.txt", puzzle.day),
+            ))
+            .is_ok_and(|s| !s.trim().is_empty())
+        };
+
+        Self {
+            day: puzzle.day,
+            solution: example_tests::get_path_for_bin(puzzle).exists(),
+            example: has_content("examples"),
+            input: has_content("inputs"),
         }
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create example file: {e}");
-            process::exit(1);
-        }
+    fn is_started(&self) -> bool {
+        self.solution || self.example || self.input
     }
 
-    println!("---");
-    println!("🎄 Type `cargo solve {}` to run your solution.", day);
+    fn is_complete(&self) -> bool {
+        self.solution && self.example && self.input
+    }
+}
+
+/// Renders the days that are missing files as a table.
+fn render_check(incomplete: &[Status]) -> Vec<String> {
+    let mark = |exists: bool| if exists { "✓" } else { "✖" };
+    let mut lines = vec![
+        "| Day | Solution | Example | Input |".to_string(),
+        "| :---: | :---: | :---: | :---: |".to_string(),
+    ];
+    lines.extend(incomplete.iter().map(|status| {
+        format!(
+            "| {} | {} | {} | {} |",
+            status.day,
+            mark(status.solution),
+            mark(status.example),
+            mark(status.input)
+        )
+    }));
+    lines
+}
+
+/// Reports the days of `year` that were started but are missing their solution, example or
+/// input, and exits with a non-zero status if there are any.
+pub fn check(year: Year) {
+    let started: Vec<Status> = all_days()
+        .map(|day| Status::read(Puzzle::new(year, day)))
+        .filter(Status::is_started)
+        .collect();
+    let incomplete: Vec<Status> = started
+        .iter()
+        .filter(|status| !status.is_complete())
+        .copied()
+        .collect();
+
+    if incomplete.is_empty() {
+        println!(
+            "All {} started days of {year} have their solution, example and input.",
+            started.len()
+        );
+        return;
+    }
+
+    for line in render_check(&incomplete) {
+        println!("{line}");
+    }
+    println!();
+    println!(
+        "Run `cargo scaffold <day> --missing-only --year {year}` to create missing files, and \
+         `cargo download <day> --year {year}` or `cargo examples <day> --year {year}` to fill \
+         them in."
+    );
+    process::exit(1);
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{render_check, render_template, title_of, Status};
+    use crate::{day, year, Puzzle};
+
+    #[test]
+    fn renders_templates() {
+        let template = "// {{title}}\nadvent_of_code::solution!({{year}}, {{day}});\n\npub fn \
+                        part_one(input: &str) -> Option<{{return_type}}> {}\n";
+        assert_eq!(
+            render_template(
+                template,
+                Puzzle::new(year!(2023), day!(8)),
+                "Day 8: Haunted Wasteland",
+                "u64"
+            ),
+            "// Day 8: Haunted Wasteland\nadvent_of_code::solution!(2023, 8);\n\npub fn \
+             part_one(input: &str) -> Option<u64> {}\n"
+        );
+    }
+
+    #[test]
+    fn reads_titles() {
+        assert_eq!(
+            title_of("## --- Day 8: Haunted Wasteland ---\n\nOne of the documents...").as_deref(),
+            Some("Day 8: Haunted Wasteland")
+        );
+        assert_eq!(title_of("no heading"), None);
+    }
+
+    #[test]
+    fn renders_missing_files() {
+        let status = Status {
+            day: day!(12),
+            solution: true,
+            example: false,
+            input: true,
+        };
+        assert_eq!(status.is_started(), true);
+        assert_eq!(status.is_complete(), false);
+        assert_eq!(
+            render_check(&[status]),
+            vec![
+                "| Day | Solution | Example | Input |",
+                "| :---: | :---: | :---: | :---: |",
+                "| 12 | ✓ | ✖ | ✓ |",
+            ]
+        );
+    }
 }
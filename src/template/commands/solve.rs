@@ -1,31 +1,256 @@
-use std::process::{Command, Stdio};
+use std::{panic, process};
 
-use crate::Day;
+use crate::{
+    template::{
+        answers::Verdict,
+        examples, get_data_path,
+        records::{PartRecord, Status, PARSE_STEP},
+        registry::{Registry, Solution},
+        runner::{self, Input, RunOptions},
+        table, ANSI_BOLD, ANSI_RESET,
+    },
+    Puzzle,
+};
 
-pub fn handle(day: Day, release: bool, time: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+/// Runs a day on its input, or on each of its example files if `examples` is set.
+pub fn handle(registry: &Registry, puzzle: Puzzle, options: &RunOptions, examples: bool) {
+    let Some(solution) = registry.get(puzzle) else {
+        eprintln!(
+            "Day {} of {} has not been scaffolded yet. Try running \"cargo scaffold {} --year \
+             {}\".",
+            puzzle.day, puzzle.year, puzzle.day, puzzle.year
+        );
+        process::exit(1);
+    };
 
-    if release {
-        cmd_args.push("--release".to_string());
+    if examples {
+        if options.submit.is_some() || options.input != Input::Puzzle {
+            eprintln!("--examples can't be combined with --submit or --input.");
+            process::exit(1);
+        }
+        run_examples(solution, options);
+        return;
     }
 
-    cmd_args.push("--".to_string());
+    let records = match runner::run_day(solution, options) {
+        Ok(records) => records,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
 
-    if let Some(submit_part) = submit_part {
-        cmd_args.push("--submit".to_string());
-        cmd_args.push(submit_part.to_string());
+    // panics are caught per part, keep reporting them through the exit status.
+    if records
+        .iter()
+        .any(|record| record.status == Status::Panicked)
+    {
+        process::exit(101);
     }
+    if records
+        .iter()
+        .any(|record| record.status == Status::Errored)
+    {
+        process::exit(1);
+    }
+}
+
+/// Runs both parts on every example file of a day and prints the expected and actual answers
+/// as a table. Exits with a non-zero status if an answer differs from the manifest, or if a part
+/// with an expected answer panicked or returned an error.
+fn run_examples(solution: &dyn Solution, options: &RunOptions) {
+    let puzzle = solution.puzzle();
+    let (year, day) = (puzzle.year, puzzle.day);
+
+    let names = match examples::list(puzzle) {
+        Ok(names) if !names.is_empty() => names,
+        Ok(_) => {
+            eprintln!(
+                "No example files for day {day} of {year}. Try running \"cargo examples {day} \
+                 --year {year}\" first."
+            );
+            process::exit(1);
+        }
+        Err(e) => {
+            eprintln!("Failed to list example files: {e}");
+            process::exit(1);
+        }
+    };
+
+    let manifest = match examples::read_manifest(puzzle) {
+        Ok(manifest) => manifest,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+
+    let mut rows = vec![["Example", "Part", "Expected", "Actual", "Time"]
+        .map(String::from)
+        .to_vec()];
+    let (mut matches, mut differs, mut failures) = (0, 0, 0);
+
+    // panics are shown in the table, keep the default hook from printing them above it.
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
 
-    if time {
-        cmd_args.push("--time".to_string());
+    for name in names {
+        let options = RunOptions {
+            input: Input::File(get_data_path(year, "examples", &format!("{name}.txt"))),
+            quiet: true,
+            ..options.clone()
+        };
+        let records = match runner::run_day(solution, &options) {
+            Ok(records) => records,
+            Err(e) => {
+                failures += 1;
+                rows.push(vec![name, String::new(), String::new(), e.to_string()]);
+                continue;
+            }
+        };
+
+        let answers = manifest.examples.get(&name);
+        for part in 1..=2 {
+            let expected = answers.and_then(|answers| answers.get(part));
+            let record = records.iter().find(|record| record.part == part);
+            let parse = records.iter().find(|record| record.part == PARSE_STEP);
+            let shown = record.or(parse);
+
+            // many examples only apply to one part, a failing part only counts if it has an
+            // expected answer.
+            if shown.is_some_and(|r| matches!(r.status, Status::Panicked | Status::Errored)) {
+                if expected.is_some() {
+                    failures += 1;
+                }
+            } else {
+                match Verdict::of(record.and_then(|r| r.answer.as_deref()), expected) {
+                    Verdict::Matches => matches += 1,
+                    Verdict::Differs => differs += 1,
+                    Verdict::Unknown => {}
+                }
+            }
+            rows.push(example_row(&name, part, expected, shown));
+        }
     }
+    panic::set_hook(hook);
 
-    let mut cmd = Command::new("cargo")
-        .args(&cmd_args)
-        .stdout(Stdio::inherit())
-        .stderr(Stdio::inherit())
-        .spawn()
-        .unwrap();
+    // rows of unreadable examples end with their error, fill in the time column.
+    for row in &mut rows {
+        row.resize(5, String::new());
+    }
+    for line in table(&rows, &[":---", "---:", ":---", ":---", "---:"]) {
+        println!("{line}");
+    }
 
-    cmd.wait().unwrap();
+    println!();
+    if differs > 0 || failures > 0 {
+        println!(
+            "{ANSI_BOLD}✗ {differs} answer(s) differ from the manifest, {failures} failed, \
+             {matches} match.{ANSI_RESET}"
+        );
+        process::exit(1);
+    }
+    if matches == 0 {
+        println!(
+            "No expected answers to compare with, add them to \"{}\".",
+            examples::get_manifest_path(puzzle).display()
+        );
+        return;
+    }
+    println!("{ANSI_BOLD}✓ {matches} answer(s) match the manifest.{ANSI_RESET}");
+}
+
+/// A row of the examples table. `record` is the parse step's record if parsing failed.
+fn example_row(
+    name: &str,
+    part: u8,
+    expected: Option<&str>,
+    record: Option<&PartRecord>,
+) -> Vec<String> {
+    let single_line = |answer: &str| {
+        if answer.contains('\n') {
+            "(multi-line)".to_string()
+        } else {
+            answer.to_string()
+        }
+    };
+
+    // messages can span lines, which would break the row.
+    let error = |record: &PartRecord| {
+        let message = record.error.as_deref().unwrap_or_default();
+        message.split_whitespace().collect::<Vec<_>>().join(" ")
+    };
+
+    let actual = match record {
+        Some(record) if record.part == PARSE_STEP => {
+            format!("✖ parse panicked: {}", error(record))
+        }
+        Some(record) => match (&record.status, &record.answer) {
+            (Status::Panicked, _) => format!("✖ panicked: {}", error(record)),
+            (Status::Errored, _) => format!("✖ error: {}", error(record)),
+            (_, Some(answer)) => match Verdict::of(Some(answer), expected) {
+                Verdict::Unknown => single_line(answer),
+                verdict => format!("{} {}", single_line(answer), verdict.symbol()),
+            },
+            (_, None) => "✖".to_string(),
+        },
+        None => "✖".to_string(),
+    };
+
+    vec![
+        name.to_string(),
+        part.to_string(),
+        expected.map_or_else(|| "?".to_string(), single_line),
+        actual,
+        record
+            .filter(|record| record.part != PARSE_STEP && record.status != Status::Panicked)
+            .map_or_else(String::new, |record| format!("{:.1?}", record.stats.median)),
+    ]
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::example_row;
+    use crate::template::records::{PartRecord, Status};
+
+    fn record(status: Status, answer: Option<&str>, error: Option<&str>) -> PartRecord {
+        PartRecord {
+            status,
+            error: error.map(Into::into),
+            ..PartRecord::mock(1, answer)
+        }
+    }
+
+    #[test]
+    fn describes_example_runs() {
+        let solved = record(Status::Solved, Some("6"), None);
+        assert_eq!(
+            example_row("08-1", 1, Some("6"), Some(&solved)),
+            vec!["08-1", "1", "6", "6 ✓", "12.0µs"]
+        );
+        assert_eq!(example_row("08-1", 1, Some("2"), Some(&solved))[3], "6 ✗");
+        assert_eq!(
+            example_row("08-1", 1, None, Some(&solved))[2..4],
+            ["?", "6"]
+        );
+
+        let errored = record(Status::Errored, None, Some("not L or R"));
+        assert_eq!(
+            example_row("08-2", 1, Some("6"), Some(&errored))[3],
+            "✖ error: not L or R"
+        );
+        assert_eq!(example_row("08-2", 2, None, None)[3..], ["✖", ""]);
+
+        let multi_line = record(Status::Errored, None, Some("not a number: xyz\n"));
+        assert_eq!(
+            example_row("08-2", 1, None, Some(&multi_line))[3],
+            "✖ error: not a number: xyz"
+        );
+
+        let panicked = record(Status::Panicked, None, Some("no entry found for key"));
+        assert_eq!(
+            example_row("08-2", 1, None, Some(&panicked))[3..],
+            ["✖ panicked: no entry found for key", ""]
+        );
+    }
 }
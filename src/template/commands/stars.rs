@@ -0,0 +1,15 @@
+use std::process;
+
+use crate::template::readme_stars;
+
+/// Updates the stars table of the readme from the recorded answers and accepted submissions of
+/// every year.
+pub fn handle() {
+    match readme_stars::update() {
+        Ok(()) => println!("Successfully updated README with stars."),
+        Err(e) => {
+            eprintln!("Failed to update readme with stars: {e}");
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use std::{
+    fs,
+    io::{self, Write},
+    path::PathBuf,
+    process,
+};
+
+use crate::{
+    template::{example_tests, examples, get_data_path, inputs},
+    Puzzle,
+};
+
+/// The files of a day that exist: its solution, input, puzzle description, example files and
+/// manifest. Recorded answers and submissions are history rather than part of the day, so they
+/// are kept.
+fn files_of(puzzle: Puzzle) -> Vec<PathBuf> {
+    let (year, day) = (puzzle.year, puzzle.day);
+    let mut files = vec![
+        example_tests::get_path_for_bin(puzzle),
+        get_data_path(year, "inputs", &format!("{day}.txt")),
+        get_data_path(year, "puzzles", &format!("{day}.md")),
+        examples::get_manifest_path(puzzle),
+    ];
+
+    if let Ok(names) = examples::list(puzzle) {
+        files.extend(
+            names
+                .iter()
+                .map(|name| get_data_path(year, "examples", &format!("{name}.txt"))),
+        );
+    }
+
+    files.retain(|path| path.exists());
+    files
+}
+
+fn confirm(prompt: &str) -> bool {
+    print!("{prompt} [y/N] ");
+    let _ = io::stdout().flush();
+
+    let mut answer = String::new();
+    io::stdin().read_line(&mut answer).is_ok()
+        && matches!(answer.trim().to_lowercase().as_str(), "y" | "yes")
+}
+
+/// Removes the files of a day after asking for confirmation, unless `yes` is set.
+pub fn handle(puzzle: Puzzle, yes: bool) {
+    let (year, day) = (puzzle.year, puzzle.day);
+    let files = files_of(puzzle);
+
+    if files.is_empty() {
+        eprintln!("Day {day} of {year} has not been scaffolded.");
+        process::exit(1);
+    }
+
+    println!("This removes:");
+    for path in &files {
+        println!("  {}", path.display());
+    }
+
+    if !yes && !confirm("Remove these files?") {
+        println!("Nothing was removed.");
+        return;
+    }
+
+    for path in &files {
+        if let Err(e) = fs::remove_file(path) {
+            eprintln!("Failed to remove \"{}\": {e}", path.display());
+            process::exit(1);
+        }
+    }
+
+    if let Err(e) = inputs::forget_checksum(puzzle) {
+        eprintln!("Failed to update input checksums: {e}");
+    }
+
+    println!("🎄 Removed day {day} of {year}. Recorded answers and submissions were kept.");
+}
@@ -0,0 +1,96 @@
+use std::process;
+
+use crate::{
+    template::{
+        answers::{self, Verdict},
+        records::{Status, PARSE_STEP},
+        registry::{Registry, Solution},
+        runner::{self, RunOptions},
+        ANSI_BOLD, ANSI_RESET,
+    },
+    Day, Puzzle, Year,
+};
+
+/// Re-runs solutions of `year` that have accepted answers and exits with a non-zero status if any
+/// answer changed. Verifies every scaffolded day of the year if `day` is [`None`].
+pub fn handle(registry: &Registry, year: Year, day: Option<Day>) {
+    let is_single_day = day.is_some();
+    let solutions: Vec<&dyn Solution> = match day {
+        Some(day) => match registry.get(Puzzle::new(year, day)) {
+            Some(solution) => vec![solution],
+            None => {
+                eprintln!(
+                    "Day {day} of {year} has not been scaffolded yet. Try running \"cargo \
+                     scaffold {day} --year {year}\"."
+                );
+                process::exit(1);
+            }
+        },
+        None => registry.year(year).collect(),
+    };
+
+    let mut matches = 0;
+    let mut failures: Vec<String> = vec![];
+
+    for solution in solutions {
+        let day = solution.puzzle().day;
+
+        match answers::read(solution.puzzle()) {
+            Ok(answers) if answers.is_empty() => {
+                if is_single_day {
+                    println!("No accepted answers recorded for day {day}.");
+                }
+                continue;
+            }
+            Ok(_) => {}
+            Err(e) => {
+                failures.push(format!("day {day}: {e}"));
+                continue;
+            }
+        }
+
+        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+        println!("------");
+
+        match runner::run_day(solution, &RunOptions::default()) {
+            Err(e) => failures.push(format!("day {day}: {e}")),
+            Ok(records) => {
+                for record in &records {
+                    if record.part == PARSE_STEP {
+                        // the parts don't run if parsing fails, so there is nothing to compare.
+                        if matches!(record.status, Status::Panicked | Status::Errored) {
+                            failures.push(format!(
+                                "day {day} parse: {}",
+                                record.error.as_deref().unwrap_or_default()
+                            ));
+                        }
+                        continue;
+                    }
+
+                    match record.verdict() {
+                        Verdict::Matches => matches += 1,
+                        Verdict::Differs => {
+                            failures.push(format!("day {day} part {}", record.part))
+                        }
+                        Verdict::Unknown => {}
+                    }
+                }
+            }
+        }
+
+        println!();
+    }
+
+    if failures.is_empty() {
+        println!("{ANSI_BOLD}✓ {matches} answer(s) match.{ANSI_RESET}");
+    } else {
+        println!(
+            "{ANSI_BOLD}✗ {} failure(s), {matches} answer(s) match:{ANSI_RESET}",
+            failures.len()
+        );
+        for failure in failures {
+            println!("  - {failure}");
+        }
+        process::exit(1);
+    }
+}
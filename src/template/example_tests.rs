@@ -0,0 +1,318 @@
+/// Module that generates a test for every example file of a day and every part with an expected
+/// answer in the day's manifest, see [`examples`](crate::template::examples). E.g. `08-1.txt`
+/// with `part_one = "6"` in the `[08-1]` section becomes:
+///
+/// ```ignore
+/// #[test]
+/// fn test_part_one_example_1() {
+///     let input = advent_of_code::template::read_file_part("examples", PUZZLE, 1);
+///     let answer = advent_of_code::template::runner::answer_text(part_one(&parse(&input)));
+///     assert_eq!(answer, Ok(Some("6".to_string())));
+/// }
+/// ```
+///
+/// Answers are compared as text, so the tests work whether a part returns an `Option` or a
+/// `Result`.
+///
+/// Generated tests are kept between two marker comments at the end of the `tests` module of a
+/// solution. Re-generating only replaces what's between the markers, so hand-written tests stay
+/// untouched.
+use std::{fmt::Display, fs, io, path::PathBuf};
+
+use crate::{
+    template::{answers, examples},
+    Puzzle,
+};
+
+pub const START_MARKER: &str = "// @generated example tests, run `cargo gen-tests` to update them.";
+pub const END_MARKER: &str = "// end of generated example tests";
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl From<answers::Error> for Error {
+    fn from(e: answers::Error) -> Self {
+        match e {
+            answers::Error::Parser(e) => Error::Parser(e),
+            answers::Error::IO(e) => Error::IO(e),
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "could not generate tests: {e}"),
+            Error::IO(e) => write!(f, "could not generate tests: {e}"),
+        }
+    }
+}
+
+/// A test of one part against one example file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExampleTest {
+    /// The number of the example file, e.g. `1` for `08-1.txt` and [`None`] for `08.txt`.
+    pub variant: Option<u8>,
+    pub part: u8,
+    pub expected: String,
+}
+
+impl ExampleTest {
+    #[must_use]
+    pub fn name(&self) -> String {
+        let part = if self.part == 1 { "one" } else { "two" };
+        match self.variant {
+            Some(variant) => format!("test_part_{part}_example_{variant}"),
+            None => format!("test_part_{part}_example"),
+        }
+    }
+
+    fn render(&self, parse: Option<&str>) -> String {
+        let read = match self.variant {
+            Some(variant) => format!("read_file_part(\"examples\", PUZZLE, {variant})"),
+            None => "read_file(\"examples\", PUZZLE)".to_string(),
+        };
+        let part = if self.part == 1 {
+            "part_one"
+        } else {
+            "part_two"
+        };
+        let call = match parse {
+            Some(parse) => format!("{part}(&{parse}(&input))"),
+            None => format!("{part}(&input)"),
+        };
+        [
+            "    #[test]".to_string(),
+            format!("    fn {}() {{", self.name()),
+            format!("        let input = advent_of_code::template::{read};"),
+            format!("        let answer = advent_of_code::template::runner::answer_text({call});"),
+            format!(
+                "        assert_eq!(answer, Ok(Some({:?}.to_string())));",
+                self.expected
+            ),
+            "    }".to_string(),
+        ]
+        .join("\n")
+    }
+}
+
+/// The tests that can be generated for a day, along with the example files and parts that lack
+/// an expected answer, e.g. `08-2 part one`.
+#[derive(Debug, Default)]
+pub struct Plan {
+    pub tests: Vec<ExampleTest>,
+    pub missing: Vec<String>,
+}
+
+/// Plans a test for every example file and part with an expected answer in the manifest.
+pub fn plan(puzzle: Puzzle) -> Result<Plan, Error> {
+    let manifest = examples::read_manifest(puzzle)?;
+    let mut plan = Plan::default();
+
+    for name in examples::list(puzzle)? {
+        // tests read numbered example files with `read_file_part`.
+        let variant = match name.strip_prefix(&format!("{}-", puzzle.day)) {
+            Some(suffix) => match suffix.parse() {
+                Ok(variant) => Some(variant),
+                Err(_) => continue,
+            },
+            None => None,
+        };
+
+        for part in 1..=2 {
+            match manifest.examples.get(&name).and_then(|a| a.get(part)) {
+                Some(expected) => plan.tests.push(ExampleTest {
+                    variant,
+                    part,
+                    expected: expected.to_string(),
+                }),
+                None => plan.missing.push(format!(
+                    "{name} part {}",
+                    if part == 1 { "one" } else { "two" }
+                )),
+            }
+        }
+    }
+
+    Ok(plan)
+}
+
+/// Reads the name of the `parse` function passed to `solution!`, if any.
+fn parse_function(source: &str) -> Option<String> {
+    let start = source.find("solution!(")? + "solution!(".len();
+    let end = start + source[start..].find(')')?;
+    source[start..end]
+        .split(',')
+        .nth(2)
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+}
+
+/// Replaces the generated tests of a solution's source, or adds them to its `tests` module.
+#[must_use]
+pub fn update_source(source: &str, tests: &[ExampleTest]) -> String {
+    let parse = parse_function(source);
+    let mut region = vec![format!("    {START_MARKER}")];
+    for (i, test) in tests.iter().enumerate() {
+        if i > 0 {
+            region.push(String::new());
+        }
+        region.push(test.render(parse.as_deref()));
+    }
+    region.push(format!("    {END_MARKER}\n"));
+    let region = region.join("\n");
+
+    if let (Some(start), Some(end)) = (source.find(START_MARKER), source.find(END_MARKER)) {
+        let start = source[..start].rfind('\n').map_or(0, |i| i + 1);
+        let end = source[end..]
+            .find('\n')
+            .map_or(source.len(), |i| end + i + 1);
+        return format!("{}{region}{}", &source[..start], &source[end..]);
+    }
+
+    // the tests module is the last item of a scaffolded solution.
+    if source.contains("mod tests {") {
+        if let Some(close) = source.rfind('}') {
+            return format!("{}\n{region}{}", &source[..close], &source[close..]);
+        }
+    }
+
+    format!(
+        "{}\n#[cfg(test)]\nmod tests {{\n    use super::*;\n\n{region}}}\n",
+        source.trim_end_matches('\n').to_string() + "\n"
+    )
+}
+
+#[must_use]
+pub fn get_path_for_bin(puzzle: Puzzle) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("src")
+        .join("bin")
+        .join(format!("{puzzle}.rs"))
+}
+
+/// Writes the planned tests of a day into its solution. Returns the plan, the solution is left
+/// alone if there's nothing to generate.
+pub fn write(puzzle: Puzzle) -> Result<Plan, Error> {
+    let plan = plan(puzzle)?;
+    let path = get_path_for_bin(puzzle);
+    let source = fs::read_to_string(&path)?;
+
+    if plan.tests.is_empty() && !source.contains(START_MARKER) {
+        return Ok(plan);
+    }
+
+    let updated = update_source(&source, &plan.tests);
+    if updated != source {
+        fs::write(path, updated)?;
+    }
+    Ok(plan)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse_function, update_source, ExampleTest, END_MARKER, START_MARKER};
+
+    const SOLUTION: &str = "advent_of_code::solution!(2023, 8, parse);
+
+pub fn part_one(map: &Map) -> Option<u32> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one_0() {
+        assert_eq!(1, 1);
+    }
+}
+";
+
+    fn tests() -> Vec<ExampleTest> {
+        vec![
+            ExampleTest {
+                variant: Some(0),
+                part: 1,
+                expected: "2".into(),
+            },
+            ExampleTest {
+                variant: None,
+                part: 2,
+                expected: "abc".into(),
+            },
+        ]
+    }
+
+    #[test]
+    fn finds_parse_functions() {
+        assert_eq!(parse_function(SOLUTION).as_deref(), Some("parse"));
+        assert_eq!(parse_function("advent_of_code::solution!(2023, 1);"), None);
+    }
+
+    #[test]
+    fn adds_tests_to_module() {
+        let updated = update_source(SOLUTION, &tests());
+        let expected = [
+            "    fn test_part_one_0() {",
+            "        assert_eq!(1, 1);",
+            "    }",
+            "",
+            &format!("    {START_MARKER}"),
+            "    #[test]",
+            "    fn test_part_one_example_0() {",
+            "        let input = advent_of_code::template::read_file_part(\"examples\", PUZZLE, \
+             0);",
+            "        let answer = \
+             advent_of_code::template::runner::answer_text(part_one(&parse(&input)));",
+            "        assert_eq!(answer, Ok(Some(\"2\".to_string())));",
+            "    }",
+            "",
+            "    #[test]",
+            "    fn test_part_two_example() {",
+            "        let input = advent_of_code::template::read_file(\"examples\", PUZZLE);",
+            "        let answer = \
+             advent_of_code::template::runner::answer_text(part_two(&parse(&input)));",
+            "        assert_eq!(answer, Ok(Some(\"abc\".to_string())));",
+            "    }",
+            &format!("    {END_MARKER}"),
+            "}",
+            "",
+        ]
+        .join("\n");
+        assert_eq!(updated.ends_with(&expected), true);
+    }
+
+    #[test]
+    fn keeps_hand_written_tests() {
+        let once = update_source(SOLUTION, &tests());
+        let twice = update_source(&once, &tests());
+        assert_eq!(once, twice);
+
+        let fewer = update_source(&once, &tests()[..1]);
+        assert_eq!(fewer.contains("fn test_part_one_0()"), true);
+        assert_eq!(fewer.contains("fn test_part_two_example()"), false);
+        assert_eq!(fewer.matches(START_MARKER).count(), 1);
+    }
+
+    #[test]
+    fn adds_missing_tests_module() {
+        let updated = update_source("advent_of_code::solution!(2023, 1);\n", &tests()[..1]);
+        assert_eq!(
+            updated.contains("\n#[cfg(test)]\nmod tests {\n    use super::*;\n\n    //"),
+            true
+        );
+        assert_eq!(updated.ends_with(&format!("    {END_MARKER}\n}}\n")), true);
+        assert_eq!(updated.contains("part_one(&input)"), true);
+    }
+}
@@ -0,0 +1,355 @@
+/// Module that extracts examples from puzzle descriptions, as downloaded to
+/// `data/<year>/puzzles/NN.md`, and keeps the expected answers of every example file in a
+/// per-day manifest `data/<year>/examples/NN.toml`, e.g.:
+///
+/// ```toml
+/// [08-0]
+/// part_one = "2"
+///
+/// [08-1]
+/// part_one = "6"
+/// ```
+use std::{collections::BTreeMap, fs, io, path::PathBuf};
+
+use crate::{
+    template::{
+        answers::{self, Answers},
+        get_data_path,
+    },
+    Puzzle,
+};
+
+/// An example found in a puzzle description.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Example {
+    pub input: String,
+    /// The answers stated near the example, if any could be found.
+    pub answers: Answers,
+}
+
+/// A paragraph or code block of a puzzle description.
+#[derive(Debug, PartialEq, Eq)]
+enum Block {
+    Text(String),
+    Code(String),
+}
+
+/// Splits markdown into paragraphs and fenced code blocks.
+fn blocks(markdown: &str) -> Vec<Block> {
+    let mut blocks = vec![];
+    let mut code: Option<String> = None;
+    let mut text = String::new();
+
+    let end_paragraph = |text: &mut String, blocks: &mut Vec<Block>| {
+        if !text.trim().is_empty() {
+            blocks.push(Block::Text(text.trim().to_string()));
+        }
+        text.clear();
+    };
+
+    for line in markdown.lines() {
+        match (&mut code, line.starts_with("```")) {
+            (None, true) => {
+                end_paragraph(&mut text, &mut blocks);
+                code = Some(String::new());
+            }
+            (Some(content), true) => {
+                blocks.push(Block::Code(std::mem::take(content)));
+                code = None;
+            }
+            (Some(content), false) => {
+                content.push_str(line);
+                content.push('\n');
+            }
+            (None, false) if line.trim().is_empty() => end_paragraph(&mut text, &mut blocks),
+            (None, false) => {
+                text.push_str(line);
+                text.push('\n');
+            }
+        }
+    }
+
+    end_paragraph(&mut text, &mut blocks);
+    blocks
+}
+
+/// Returns the emphasized code in a text, e.g. `142` in "produces *`142`*", in order.
+fn emphasized_values(text: &str) -> Vec<String> {
+    let mut values = vec![];
+    let mut rest = text;
+
+    while let Some(start) = rest.find("*`") {
+        rest = &rest[start + 2..];
+        let Some(end) = rest.find("`*") else {
+            break;
+        };
+        values.push(rest[..end].to_string());
+        rest = &rest[end + 2..];
+    }
+
+    values
+}
+
+/// Whether the block at `i` is code that is introduced as an example, e.g. "For example:".
+fn is_example(blocks: &[Block], i: usize) -> bool {
+    matches!(blocks[i], Block::Code(_))
+        && i > 0
+        && matches!(&blocks[i - 1], Block::Text(text) if text.to_lowercase().contains("example"))
+}
+
+/// The last emphasized value in the text of `blocks`.
+fn last_emphasized_value(blocks: &[Block]) -> Option<String> {
+    blocks.iter().rev().find_map(|block| match block {
+        Block::Text(text) => emphasized_values(text).pop(),
+        Block::Code(_) => None,
+    })
+}
+
+/// Finds the examples of one part of a puzzle. Returns each example with the last value that
+/// is emphasized in the text after it, which is where puzzles usually state the answer.
+fn examples_of_part(blocks: &[Block]) -> Vec<(String, Option<String>)> {
+    let mut indices: Vec<usize> = (0..blocks.len())
+        .filter(|&i| is_example(blocks, i))
+        .collect();
+    // not every puzzle calls its example one.
+    if indices.is_empty() {
+        indices.extend(blocks.iter().position(|b| matches!(b, Block::Code(_))));
+    }
+
+    indices
+        .iter()
+        .enumerate()
+        .filter_map(|(n, &i)| {
+            let Block::Code(input) = &blocks[i] else {
+                return None;
+            };
+            let end = indices.get(n + 1).copied().unwrap_or(blocks.len());
+            Some((input.clone(), last_emphasized_value(&blocks[i + 1..end])))
+        })
+        .collect()
+}
+
+/// Extracts the examples from the markdown of a puzzle description, see
+/// [`html::to_markdown`](crate::template::html::to_markdown). Examples of the second part that
+/// repeat an example of the first part are merged into it.
+#[must_use]
+pub fn extract(markdown: &str) -> Vec<Example> {
+    let blocks = blocks(markdown);
+    let part_two_start = blocks
+        .iter()
+        .position(|block| matches!(block, Block::Text(text) if text.contains("--- Part Two ---")))
+        .unwrap_or(blocks.len());
+    let (part_one, part_two) = blocks.split_at(part_two_start);
+
+    let mut examples: Vec<Example> = examples_of_part(part_one)
+        .into_iter()
+        .map(|(input, answer)| Example {
+            input,
+            answers: Answers {
+                part_one: answer,
+                part_two: None,
+            },
+        })
+        .collect();
+
+    if (0..part_two.len()).any(|i| is_example(part_two, i)) {
+        for (input, answer) in examples_of_part(part_two) {
+            match examples.iter_mut().find(|example| example.input == input) {
+                Some(example) => example.answers.part_two = answer,
+                None => examples.push(Example {
+                    input,
+                    answers: Answers {
+                        part_one: None,
+                        part_two: answer,
+                    },
+                }),
+            }
+        }
+    } else if let Some(example) = examples.first_mut() {
+        // the second part usually refers back to the example of the first part.
+        example.answers.part_two = last_emphasized_value(part_two);
+    }
+
+    examples
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The expected answers of the example files of a day, keyed by file name without extension.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Manifest {
+    pub examples: BTreeMap<String, Answers>,
+}
+
+impl Manifest {
+    pub fn parse(s: &str) -> Result<Self, answers::Error> {
+        let mut examples = BTreeMap::new();
+        let mut current: Option<(String, String)> = None;
+
+        for line in s.lines() {
+            let trimmed = line.trim();
+            if let Some(name) = trimmed.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                if let Some((name, content)) = current.take() {
+                    examples.insert(name, Answers::parse(&content)?);
+                }
+                current = Some((name.trim().to_string(), String::new()));
+            } else if let Some((_, content)) = &mut current {
+                content.push_str(line);
+                content.push('\n');
+            } else if !(trimmed.is_empty() || trimmed.starts_with('#')) {
+                return Err(answers::Error::Parser(format!(
+                    "expected an `[example]` header: {trimmed}"
+                )));
+            }
+        }
+
+        if let Some((name, content)) = current {
+            examples.insert(name, Answers::parse(&content)?);
+        }
+
+        Ok(Self { examples })
+    }
+
+    #[must_use]
+    pub fn to_toml(&self) -> String {
+        self.examples
+            .iter()
+            .map(|(name, answers)| format!("[{name}]\n{}", answers.to_toml()))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+#[must_use]
+pub fn get_manifest_path(puzzle: Puzzle) -> PathBuf {
+    get_data_path(puzzle.year, "examples", &format!("{}.toml", puzzle.day))
+}
+
+/// The names of a day's example files without extension, i.e. `NN` and `NN-<suffix>`, with `NN`
+/// first and numbered ones in numeric order.
+pub fn list(puzzle: Puzzle) -> io::Result<Vec<String>> {
+    let day = puzzle.day.to_string();
+    let dir = get_data_path(puzzle.year, "examples", "");
+    if !dir.exists() {
+        return Ok(vec![]);
+    }
+
+    let mut names: Vec<String> = fs::read_dir(dir)?
+        .filter_map(|entry| {
+            let file_name = entry.ok()?.file_name().to_str()?.to_string();
+            let name = file_name.strip_suffix(".txt")?;
+            (name == day || name.starts_with(&format!("{day}-"))).then(|| name.to_string())
+        })
+        .collect();
+
+    names.sort_by_key(|name| {
+        let suffix = name.strip_prefix(&format!("{day}-"));
+        let number = suffix.and_then(|s| s.parse::<u64>().ok());
+        (suffix.is_some(), number.is_none(), number, name.clone())
+    });
+    Ok(names)
+}
+
+/// Reads the manifest of a day. Days without a manifest have no known example answers.
+pub fn read_manifest(puzzle: Puzzle) -> Result<Manifest, answers::Error> {
+    let path = get_manifest_path(puzzle);
+    if !path.exists() {
+        return Ok(Manifest::default());
+    }
+    Manifest::parse(&fs::read_to_string(path)?)
+}
+
+pub fn write_manifest(puzzle: Puzzle, manifest: &Manifest) -> Result<(), answers::Error> {
+    let path = get_manifest_path(puzzle);
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, manifest.to_toml())?;
+    Ok(())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{emphasized_values, extract, Manifest};
+    use crate::template::answers::Answers;
+
+    const PUZZLE: &str = "## --- Day 8: Haunted Wasteland ---
+
+One of the documents contains a list of left/right instructions. For example:
+
+```
+RL
+
+AAA = (BBB, CCC)
+```
+
+Starting with `AAA`, you need to look up the next element. Here, *`2`* steps are required.
+
+Here's another example:
+
+```
+LLR
+
+AAA = (BBB, BBB)
+```
+
+This takes *`6`* steps.
+
+## --- Part Two ---
+
+The sandstorm is upon you. For example:
+
+```
+LR
+
+11A = (11B, XXX)
+```
+
+Here, it takes *`6`* steps.
+";
+
+    fn answers(part_one: Option<&str>, part_two: Option<&str>) -> Answers {
+        Answers {
+            part_one: part_one.map(Into::into),
+            part_two: part_two.map(Into::into),
+        }
+    }
+
+    #[test]
+    fn finds_emphasized_values() {
+        assert_eq!(
+            emphasized_values("values *`12`*, *`38`* and `77` add up to *`142`*."),
+            vec!["12", "38", "142"]
+        );
+    }
+
+    #[test]
+    fn extracts_examples() {
+        let examples = extract(PUZZLE);
+        assert_eq!(examples.len(), 3);
+        assert_eq!(examples[0].input, "RL\n\nAAA = (BBB, CCC)\n");
+        assert_eq!(examples[0].answers, answers(Some("2"), None));
+        assert_eq!(examples[1].answers, answers(Some("6"), None));
+        assert_eq!(examples[2].input, "LR\n\n11A = (11B, XXX)\n");
+        assert_eq!(examples[2].answers, answers(None, Some("6")));
+    }
+
+    #[test]
+    fn reuses_example_for_part_two() {
+        let markdown = "Consider this list:\n\n```\n1abc2\n```\n\nThis produces *`12`*.\n\n## --- \
+                        Part Two ---\n\nNow the same list adds up to *`281`*.\n";
+        let examples = extract(markdown);
+        assert_eq!(examples.len(), 1);
+        assert_eq!(examples[0].answers, answers(Some("12"), Some("281")));
+    }
+
+    #[test]
+    fn roundtrips_manifest() {
+        let manifest =
+            Manifest::parse("[08-0]\npart_one = \"2\"\n\n[08-1]\npart_two = \"6\"\n").unwrap();
+        assert_eq!(manifest.examples["08-0"], answers(Some("2"), None));
+        assert_eq!(manifest.examples["08-1"], answers(None, Some("6")));
+        assert_eq!(Manifest::parse(&manifest.to_toml()).unwrap(), manifest);
+        assert_eq!(Manifest::parse("part_one = \"2\"").is_err(), true);
+    }
+}
@@ -0,0 +1,312 @@
+//! Just enough HTML handling for the pages served by Advent of Code: converting puzzle
+//! descriptions to markdown and reading the text of submission responses.
+
+/// A piece of an HTML document.
+#[derive(Debug, PartialEq, Eq)]
+enum Token<'a> {
+    Open { name: String, attrs: &'a str },
+    Close { name: String },
+    Text(&'a str),
+}
+
+fn tokenize(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = vec![];
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            tokens.push(Token::Text(rest));
+            break;
+        };
+
+        if start > 0 {
+            tokens.push(Token::Text(&rest[..start]));
+        }
+
+        let Some(end) = rest[start..].find('>').map(|end| start + end) else {
+            tokens.push(Token::Text(&rest[start..]));
+            break;
+        };
+
+        let tag = &rest[start + 1..end];
+        rest = &rest[end + 1..];
+
+        // comments, doctypes and processing instructions carry no content.
+        if tag.starts_with('!') || tag.starts_with('?') {
+            continue;
+        }
+
+        let tag = tag.trim_end_matches('/');
+        if let Some(name) = tag.strip_prefix('/') {
+            tokens.push(Token::Close {
+                name: name.trim().to_ascii_lowercase(),
+            });
+        } else {
+            let (name, attrs) = tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+            tokens.push(Token::Open {
+                name: name.to_ascii_lowercase(),
+                attrs,
+            });
+        }
+    }
+
+    tokens
+}
+
+/// Reads the value of an attribute, e.g. `href` in `href="/2023/day/1"`.
+fn attribute<'a>(attrs: &'a str, name: &str) -> Option<&'a str> {
+    let pattern = format!("{name}=\"");
+    let start = attrs.find(&pattern)? + pattern.len();
+    let end = attrs[start..].find('"')? + start;
+    Some(&attrs[start..end])
+}
+
+/// Replaces the character references that commonly appear in puzzle pages.
+#[must_use]
+pub fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let entity = rest.find(';').map(|end| &rest[1..end]).and_then(|name| {
+            let c = match name {
+                "lt" => '<',
+                "gt" => '>',
+                "amp" => '&',
+                "quot" => '"',
+                "apos" => '\'',
+                "nbsp" => ' ',
+                _ => {
+                    let code = match name.strip_prefix("#x") {
+                        Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                        None => name.strip_prefix('#')?.parse().ok()?,
+                    };
+                    char::from_u32(code)?
+                }
+            };
+            Some((c, name.len() + 2))
+        });
+
+        match entity {
+            Some((c, len)) => {
+                decoded.push(c);
+                rest = &rest[len..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    decoded.push_str(rest);
+    decoded
+}
+
+/// Returns the inner HTML of every `<article>` element.
+fn articles(html: &str) -> Vec<&str> {
+    let mut articles = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article") {
+        let Some(content_start) = rest[start..].find('>').map(|i| start + i + 1) else {
+            break;
+        };
+        let Some(end) = rest[content_start..]
+            .find("</article>")
+            .map(|i| content_start + i)
+        else {
+            break;
+        };
+
+        articles.push(&rest[content_start..end]);
+        rest = &rest[end..];
+    }
+
+    articles
+}
+
+/// Returns the text inside the `<article>` elements of a page, without any markup.
+#[must_use]
+pub fn article_text(html: &str) -> String {
+    let text: String = articles(html)
+        .into_iter()
+        .flat_map(tokenize)
+        .filter_map(|token| match token {
+            Token::Text(text) => Some(text),
+            _ => None,
+        })
+        .collect();
+
+    decode_entities(&text)
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Converts the puzzle descriptions of a puzzle page to markdown.
+#[must_use]
+pub fn to_markdown(html: &str) -> String {
+    let mut markdown = String::new();
+
+    for article in articles(html) {
+        let mut in_pre = false;
+        let mut in_code = false;
+        // answers are marked as `<code><em>42</em></code>`, which becomes *`42`*.
+        let mut is_code_emphasized = false;
+        let mut links: Vec<String> = vec![];
+
+        for token in tokenize(article) {
+            match token {
+                Token::Text(text) if in_pre => markdown.push_str(&decode_entities(text)),
+                Token::Text(text) => {
+                    let text = decode_entities(text).replace('\n', " ");
+                    // drop indentation between block elements.
+                    if !(markdown.is_empty() || markdown.ends_with('\n')) || !text.trim().is_empty()
+                    {
+                        markdown.push_str(&text);
+                    }
+                }
+                Token::Open { name, attrs } => match name.as_str() {
+                    "h2" => markdown.push_str("## "),
+                    "pre" => {
+                        in_pre = true;
+                        markdown.push_str("```\n");
+                    }
+                    "code" if !in_pre => {
+                        in_code = true;
+                        markdown.push('`');
+                    }
+                    "em" if in_code && markdown.ends_with('`') => {
+                        markdown.pop();
+                        markdown.push_str("*`");
+                        is_code_emphasized = true;
+                    }
+                    // markdown has no emphasis inside of code.
+                    "em" if !in_pre && !in_code => markdown.push('*'),
+                    "li" => markdown.push_str("- "),
+                    "br" => markdown.push('\n'),
+                    "a" => {
+                        links.push(attribute(attrs, "href").unwrap_or_default().to_string());
+                        markdown.push('[');
+                    }
+                    _ => {}
+                },
+                Token::Close { name } => match name.as_str() {
+                    "h2" | "p" | "ul" => markdown.push_str("\n\n"),
+                    "pre" => {
+                        in_pre = false;
+                        if !markdown.ends_with('\n') {
+                            markdown.push('\n');
+                        }
+                        markdown.push_str("```\n\n");
+                    }
+                    "code" if !in_pre => {
+                        in_code = false;
+                        markdown.push('`');
+                        if is_code_emphasized {
+                            markdown.push('*');
+                            is_code_emphasized = false;
+                        }
+                    }
+                    "em" if !in_pre && !in_code => markdown.push('*'),
+                    "li" => markdown.push('\n'),
+                    "a" => {
+                        let href = links.pop().unwrap_or_default();
+                        markdown.push_str(&format!("]({href})"));
+                    }
+                    _ => {}
+                },
+            }
+        }
+    }
+
+    tidy(&markdown)
+}
+
+/// Removes trailing whitespace and collapses runs of blank lines.
+fn tidy(markdown: &str) -> String {
+    let mut tidied = String::new();
+    let mut blank_lines = 0;
+
+    for line in markdown.lines() {
+        let line = line.trim_end();
+        if line.is_empty() {
+            blank_lines += 1;
+            if blank_lines > 1 {
+                continue;
+            }
+        } else {
+            blank_lines = 0;
+        }
+        tidied.push_str(line);
+        tidied.push('\n');
+    }
+
+    tidied.trim().to_string() + "\n"
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{article_text, decode_entities, to_markdown};
+
+    const PUZZLE_PAGE: &str = r#"<!DOCTYPE html>
+<html><body><main>
+<article class="day-desc"><h2>--- Day 1: Trebuchet?! ---</h2><p>Something is wrong with <em>global snow production</em>, see <a href="/2023/about">here</a>.</p>
+<p>For example:</p>
+<pre><code>1abc2
+pqr3stu8vwx
+</code></pre>
+<ul>
+<li>In <code>1abc2</code>, the value is <code><em>12</em></code>.</li>
+<li>Values &lt; 10 &amp; &gt; 0.</li>
+</ul>
+</article>
+<p>Your puzzle answer was <code>54304</code>.</p>
+</main></body></html>"#;
+
+    #[test]
+    fn converts_puzzle_to_markdown() {
+        assert_eq!(
+            to_markdown(PUZZLE_PAGE),
+            [
+                "## --- Day 1: Trebuchet?! ---",
+                "",
+                "Something is wrong with *global snow production*, see [here](/2023/about).",
+                "",
+                "For example:",
+                "",
+                "```",
+                "1abc2",
+                "pqr3stu8vwx",
+                "```",
+                "",
+                "- In `1abc2`, the value is *`12`*.",
+                "- Values < 10 & > 0.",
+                "",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn reads_article_text() {
+        let html = "<main><article><p>That's the <em>right answer</em>!\n  You are\n one gold \
+                    star closer.</p></article></main>";
+        assert_eq!(
+            article_text(html),
+            "That's the right answer! You are one gold star closer."
+        );
+    }
+
+    #[test]
+    fn decodes_entities() {
+        assert_eq!(
+            decode_entities("a &lt;b&gt; &#39;c&#x27; &unknown; &"),
+            "a <b> 'c' &unknown; &"
+        );
+    }
+}
@@ -0,0 +1,174 @@
+/// Module that checks downloaded puzzle inputs before solutions run on them, so a day that was
+/// scaffolded but not downloaded fails with a hint instead of a panic deep inside parsing.
+///
+/// `cargo download` records a checksum of every input it writes in
+/// `data/<year>/inputs/checksums.txt`, one `<checksum>  NN.txt` line per input. Inputs that no
+/// longer match their checksum have most likely been edited by accident.
+use std::{collections::BTreeMap, fmt::Display, fs, io, path::PathBuf};
+
+use crate::{
+    template::{get_data_path, try_read_file, ReadFileError},
+    Puzzle, Year,
+};
+
+#[derive(Debug)]
+pub enum Error {
+    /// The input file is missing, empty or only contains whitespace.
+    NotDownloaded(Puzzle),
+    /// The input does not end with a newline, as every downloaded input does.
+    Truncated(Puzzle),
+    Read(ReadFileError),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::NotDownloaded(puzzle) => write!(
+                f,
+                "input not downloaded, run `cargo download {} --year {}`",
+                puzzle.day, puzzle.year
+            ),
+            Error::Truncated(puzzle) => write!(
+                f,
+                "input looks truncated, run `cargo download {} --year {}` to download it again",
+                puzzle.day, puzzle.year
+            ),
+            Error::Read(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ReadFileError> for Error {
+    fn from(e: ReadFileError) -> Self {
+        Error::Read(e)
+    }
+}
+
+/// Reads the downloaded input of a puzzle and checks that it looks complete.
+pub fn read(puzzle: Puzzle) -> Result<String, Error> {
+    let input = match try_read_file("inputs", puzzle) {
+        Ok(input) => input,
+        Err(e) if e.source.kind() == io::ErrorKind::NotFound => {
+            return Err(Error::NotDownloaded(puzzle))
+        }
+        Err(e) => return Err(e.into()),
+    };
+    check(puzzle, &input)?;
+    Ok(input)
+}
+
+/// Checks that an input is neither empty nor cut off.
+pub fn check(puzzle: Puzzle, input: &str) -> Result<(), Error> {
+    if input.trim().is_empty() {
+        Err(Error::NotDownloaded(puzzle))
+    } else if !input.ends_with('\n') {
+        Err(Error::Truncated(puzzle))
+    } else {
+        Ok(())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A 64-bit FNV-1a hash of an input, which is plenty to notice accidental edits.
+#[must_use]
+pub fn checksum(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    });
+    format!("{hash:016x}")
+}
+
+#[must_use]
+pub fn get_checksums_path(year: Year) -> PathBuf {
+    get_data_path(year, "inputs", "checksums.txt")
+}
+
+/// Parses a checksums file into checksums keyed by file name.
+fn parse_checksums(s: &str) -> BTreeMap<String, String> {
+    s.lines()
+        .filter_map(|line| line.split_once("  "))
+        .map(|(checksum, file_name)| (file_name.trim().to_string(), checksum.trim().to_string()))
+        .collect()
+}
+
+fn read_checksums(year: Year) -> BTreeMap<String, String> {
+    fs::read_to_string(get_checksums_path(year))
+        .map(|s| parse_checksums(&s))
+        .unwrap_or_default()
+}
+
+fn write_checksums(year: Year, checksums: &BTreeMap<String, String>) -> io::Result<()> {
+    let path = get_checksums_path(year);
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(
+        path,
+        checksums
+            .iter()
+            .map(|(file_name, checksum)| format!("{checksum}  {file_name}\n"))
+            .collect::<String>(),
+    )
+}
+
+/// Records the checksum of a freshly downloaded input.
+pub fn record_checksum(puzzle: Puzzle, input: &str) -> io::Result<()> {
+    let mut checksums = read_checksums(puzzle.year);
+    checksums.insert(format!("{}.txt", puzzle.day), checksum(input));
+    write_checksums(puzzle.year, &checksums)
+}
+
+/// Removes the checksum of an input that was deleted.
+pub fn forget_checksum(puzzle: Puzzle) -> io::Result<()> {
+    let mut checksums = read_checksums(puzzle.year);
+    if checksums.remove(&format!("{}.txt", puzzle.day)).is_some() {
+        write_checksums(puzzle.year, &checksums)?;
+    }
+    Ok(())
+}
+
+/// Whether an input differs from the one that was downloaded. Inputs without a recorded
+/// checksum, e.g. downloaded before checksums were kept, are never considered modified.
+#[must_use]
+pub fn is_modified(puzzle: Puzzle, input: &str) -> bool {
+    read_checksums(puzzle.year)
+        .get(&format!("{}.txt", puzzle.day))
+        .is_some_and(|recorded| *recorded != checksum(input))
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{check, checksum, parse_checksums};
+    use crate::{day, year, Puzzle};
+
+    #[test]
+    fn detects_incomplete_inputs() {
+        let puzzle = Puzzle::new(year!(2023), day!(5));
+        assert_eq!(check(puzzle, "seeds: 79 14\n").is_ok(), true);
+
+        let err = check(puzzle, " \n\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "input not downloaded, run `cargo download 05 --year 2023`"
+        );
+        assert_eq!(check(puzzle, "").is_err(), true);
+        assert_eq!(
+            check(puzzle, "seeds: 79 1").unwrap_err().to_string(),
+            "input looks truncated, run `cargo download 05 --year 2023` to download it again"
+        );
+    }
+
+    #[test]
+    fn computes_checksums() {
+        assert_eq!(checksum(""), "cbf29ce484222325");
+        assert_eq!(checksum("a"), "af63dc4c8601ec8c");
+        assert_eq!(checksum("1abc2\n") == checksum("1abc3\n"), false);
+
+        let checksums = parse_checksums("af63dc4c8601ec8c  01.txt\ncbf29ce484222325  02.txt\n");
+        assert_eq!(checksums["01.txt"], "af63dc4c8601ec8c");
+        assert_eq!(checksums.len(), 2);
+    }
+}
@@ -0,0 +1,435 @@
+/// Module that reads and renders private leaderboards, as served by the Advent of Code API at
+/// `/<year>/leaderboard/private/view/<id>.json`:
+///
+/// ```json
+/// {"event": "2023", "owner_id": 1, "members": {"1": {
+///     "id": 1, "name": "Ada", "local_score": 12, "stars": 3, "last_star_ts": 1701409000,
+///     "completion_day_level": {"1": {"1": {"get_star_ts": 1701408000, "star_index": 0}}}
+/// }}}
+/// ```
+///
+/// Leaderboards are cached in `data/<year>/leaderboards/<id>.json`. When a fetched leaderboard
+/// differs from the cached one, the cached one is kept as `<id>.previous.json` to show how ranks
+/// changed since.
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    time::UNIX_EPOCH,
+};
+
+use tinyjson::JsonValue;
+
+use crate::{
+    template::{get_data_path, table},
+    Day, Year,
+};
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "could not parse leaderboard: {e}"),
+            Error::IO(e) => write!(f, "i/o error: {e}"),
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Member {
+    pub id: u64,
+    /// [`None`] for members that don't share their name.
+    pub name: Option<String>,
+    pub local_score: u64,
+    pub stars: u32,
+    /// Seconds since the unix epoch, `0` if the member has no stars yet.
+    pub last_star_ts: u64,
+    /// When each star was earned, keyed by day and part, in seconds since the unix epoch.
+    pub completions: BTreeMap<(Day, u8), u64>,
+}
+
+impl Member {
+    /// The name of the member, or the placeholder the website shows for anonymous members.
+    #[must_use]
+    pub fn display_name(&self) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| format!("(anonymous user #{})", self.id))
+    }
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn from_json(value: &JsonValue) -> Option<Self> {
+        let object: &HashMap<_, _> = value.get()?;
+
+        let mut completions = BTreeMap::new();
+        let days: &HashMap<_, _> = object.get("completion_day_level")?.get()?;
+        for (day, parts) in days {
+            let day = day.parse().ok()?;
+            let parts: &HashMap<_, _> = parts.get()?;
+            for (part, star) in parts {
+                let star: &HashMap<_, _> = star.get()?;
+                completions.insert((day, part.parse().ok()?), number(star.get("get_star_ts")?)?);
+            }
+        }
+
+        Some(Self {
+            id: number(object.get("id")?)?,
+            name: match object.get("name") {
+                Some(JsonValue::String(name)) => Some(name.clone()),
+                _ => None,
+            },
+            local_score: number(object.get("local_score")?)?,
+            stars: number(object.get("stars")?)? as u32,
+            last_star_ts: number(object.get("last_star_ts")?)?,
+            completions,
+        })
+    }
+}
+
+/// Reads an integer that is either a number or a string, the API has used both over the years.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn number(value: &JsonValue) -> Option<u64> {
+    match value {
+        JsonValue::Number(n) => Some(*n as u64),
+        JsonValue::String(s) => s.parse().ok(),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Leaderboard {
+    pub year: Year,
+    pub members: Vec<Member>,
+}
+
+impl Leaderboard {
+    pub fn parse(json: &str) -> Result<Self, Error> {
+        let value: JsonValue = json
+            .parse()
+            .map_err(|_| Error::Parser("response is not JSON".into()))?;
+        let object: &HashMap<_, _> = value
+            .get()
+            .ok_or_else(|| Error::Parser("expected an object".into()))?;
+
+        let year = object
+            .get("event")
+            .and_then(|event| event.get::<String>())
+            .and_then(|event| event.parse().ok())
+            .ok_or_else(|| Error::Parser("missing or invalid event".into()))?;
+
+        let members: &HashMap<_, _> = object
+            .get("members")
+            .and_then(JsonValue::get)
+            .ok_or_else(|| Error::Parser("missing members".into()))?;
+
+        let members = members
+            .iter()
+            .map(|(id, member)| {
+                Member::from_json(member)
+                    .ok_or_else(|| Error::Parser(format!("malformed member {id}")))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { year, members })
+    }
+
+    /// The members ordered by local score. Ties go to whoever got their last star first.
+    #[must_use]
+    pub fn ranked(&self) -> Vec<&Member> {
+        let mut members: Vec<&Member> = self.members.iter().collect();
+        members.sort_by_key(|m| (std::cmp::Reverse(m.local_score), m.last_star_ts, m.id));
+        members
+    }
+
+    /// The latest day anyone has a star for.
+    #[must_use]
+    pub fn last_day(&self) -> Option<Day> {
+        self.members
+            .iter()
+            .flat_map(|member| member.completions.keys())
+            .map(|(day, _)| *day)
+            .max()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// How the rank of a member changed between two snapshots of a leaderboard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RankChange {
+    /// The member was not on the previous snapshot.
+    New,
+    Up(usize),
+    Down(usize),
+    Same,
+}
+
+impl Display for RankChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RankChange::New => write!(f, "new"),
+            RankChange::Up(n) => write!(f, "▲ {n}"),
+            RankChange::Down(n) => write!(f, "▼ {n}"),
+            RankChange::Same => Ok(()),
+        }
+    }
+}
+
+/// Compares the ranks of the members of two snapshots, keyed by member id.
+#[must_use]
+pub fn rank_changes(current: &Leaderboard, previous: &Leaderboard) -> HashMap<u64, RankChange> {
+    let previous_ranks: HashMap<u64, usize> = previous
+        .ranked()
+        .iter()
+        .enumerate()
+        .map(|(rank, member)| (member.id, rank))
+        .collect();
+
+    current
+        .ranked()
+        .iter()
+        .enumerate()
+        .map(|(rank, member)| {
+            let change = match previous_ranks.get(&member.id) {
+                None => RankChange::New,
+                Some(&before) if before > rank => RankChange::Up(before - rank),
+                Some(&before) if before < rank => RankChange::Down(rank - before),
+                Some(_) => RankChange::Same,
+            };
+            (member.id, change)
+        })
+        .collect()
+}
+
+/// Formats the time it took to earn a star like the website does, e.g. `01:02:03` or `>24h`.
+fn format_elapsed(seconds: u64) -> String {
+    if seconds >= 24 * 60 * 60 {
+        return ">24h".into();
+    }
+    format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+/// Renders a leaderboard as a table of members by rank, with their score, a star for every day
+/// (`★` both parts, `☆` the first part only) and the time they took for each part of `day`.
+/// Shows how ranks changed when a `previous` snapshot is given.
+#[must_use]
+pub fn render(leaderboard: &Leaderboard, previous: Option<&Leaderboard>, day: Day) -> Vec<String> {
+    let changes = previous.map(|previous| rank_changes(leaderboard, previous));
+    let days = leaderboard.last_day().map_or(0, Day::into_inner);
+    let unlock = day
+        .unlocks_at(leaderboard.year)
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+
+    let mut rows = vec![vec![
+        "Rank".to_string(),
+        "Change".to_string(),
+        "Score".to_string(),
+        format!("Days 1-{days}"),
+        format!("Day {day} part 1"),
+        format!("Day {day} part 2"),
+        "Name".to_string(),
+    ]];
+
+    for (rank, member) in leaderboard.ranked().iter().enumerate() {
+        let stars: String = (1..=days)
+            .filter_map(Day::new)
+            .map(|d| {
+                match (
+                    member.completions.contains_key(&(d, 1)),
+                    member.completions.contains_key(&(d, 2)),
+                ) {
+                    (true, true) => '★',
+                    (true, false) => '☆',
+                    _ => '·',
+                }
+            })
+            .collect();
+        let elapsed = |part: u8| {
+            member
+                .completions
+                .get(&(day, part))
+                .map_or(String::new(), |ts| {
+                    format_elapsed(ts.saturating_sub(unlock))
+                })
+        };
+
+        rows.push(vec![
+            (rank + 1).to_string(),
+            changes
+                .as_ref()
+                .and_then(|changes| changes.get(&member.id))
+                .map_or(String::new(), ToString::to_string),
+            member.local_score.to_string(),
+            stars,
+            elapsed(1),
+            elapsed(2),
+            member.display_name(),
+        ]);
+    }
+
+    table(
+        &rows,
+        &["---:", ":---", "---:", ":---", "---:", "---:", ":---"],
+    )
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[must_use]
+pub fn get_path(year: Year, id: u64) -> PathBuf {
+    get_data_path(year, "leaderboards", &format!("{id}.json"))
+}
+
+#[must_use]
+pub fn get_previous_path(year: Year, id: u64) -> PathBuf {
+    get_data_path(year, "leaderboards", &format!("{id}.previous.json"))
+}
+
+/// Reads a cached leaderboard, [`None`] if there is none.
+pub fn read(path: &Path) -> Result<Option<Leaderboard>, Error> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    Leaderboard::parse(&fs::read_to_string(path)?).map(Some)
+}
+
+/// Caches the JSON of a fetched leaderboard. If it differs from the cached one, that one becomes
+/// the previous snapshot.
+pub fn store(year: Year, id: u64, json: &str) -> Result<(), Error> {
+    let path = get_path(year, id);
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    match fs::read_to_string(&path) {
+        Ok(cached) if cached == json => return Ok(()),
+        Ok(_) => fs::rename(&path, get_previous_path(year, id))?,
+        Err(_) => {}
+    }
+
+    fs::write(path, json)?;
+    Ok(())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{format_elapsed, rank_changes, render, Leaderboard, RankChange};
+    use crate::{day, year};
+
+    const LEADERBOARD: &str = r#"{
+        "event": "2023",
+        "owner_id": 1,
+        "members": {
+            "1": {
+                "id": 1, "name": "Ada", "local_score": 10, "stars": 3, "global_score": 0,
+                "last_star_ts": 1701500000,
+                "completion_day_level": {
+                    "1": {
+                        "1": {"get_star_ts": 1701407100, "star_index": 0},
+                        "2": {"get_star_ts": 1701410000, "star_index": 3}
+                    },
+                    "2": {"1": {"get_star_ts": 1701500000, "star_index": 7}}
+                }
+            },
+            "2": {
+                "id": 2, "name": null, "local_score": 10, "stars": 2, "global_score": 0,
+                "last_star_ts": 1701420000,
+                "completion_day_level": {
+                    "1": {
+                        "1": {"get_star_ts": "1701407000", "star_index": 1},
+                        "2": {"get_star_ts": 1701420000, "star_index": 5}
+                    }
+                }
+            },
+            "3": {
+                "id": 3, "name": "Grace", "local_score": 0, "stars": 0, "global_score": 0,
+                "last_star_ts": 0, "completion_day_level": {}
+            }
+        }
+    }"#;
+
+    #[test]
+    fn parses_leaderboards() {
+        let leaderboard = Leaderboard::parse(LEADERBOARD).unwrap();
+        assert_eq!(leaderboard.year, year!(2023));
+        assert_eq!(leaderboard.last_day(), Some(day!(2)));
+
+        let ranked = leaderboard.ranked();
+        assert_eq!(
+            ranked.iter().map(|m| m.display_name()).collect::<Vec<_>>(),
+            vec!["(anonymous user #2)", "Ada", "Grace"]
+        );
+        assert_eq!(ranked[0].completions[&(day!(1), 1)], 1_701_407_000);
+        assert_eq!(ranked[1].completions.len(), 3);
+
+        assert_eq!(Leaderboard::parse("<html></html>").is_err(), true);
+        assert_eq!(Leaderboard::parse(r#"{"event": "2023"}"#).is_err(), true);
+    }
+
+    #[test]
+    fn computes_rank_changes() {
+        let previous = Leaderboard::parse(LEADERBOARD).unwrap();
+        let mut current = previous.clone();
+        current.members.retain(|m| m.id != 3);
+        current.members.iter_mut().for_each(|m| {
+            if m.id == 1 {
+                m.local_score = 15;
+            }
+        });
+        let mut newcomer = previous.members.iter().find(|m| m.id == 3).unwrap().clone();
+        newcomer.id = 4;
+        current.members.push(newcomer);
+
+        let changes = rank_changes(&current, &previous);
+        assert_eq!(changes[&1], RankChange::Up(1));
+        assert_eq!(changes[&2], RankChange::Down(1));
+        assert_eq!(changes[&4], RankChange::New);
+        assert_eq!(changes.contains_key(&3), false);
+    }
+
+    #[test]
+    fn formats_elapsed_times() {
+        assert_eq!(format_elapsed(3723), "01:02:03");
+        assert_eq!(format_elapsed(90_000), ">24h");
+    }
+
+    #[test]
+    fn renders_tables() {
+        let leaderboard = Leaderboard::parse(LEADERBOARD).unwrap();
+        let lines = render(&leaderboard, None, day!(1));
+        assert_eq!(lines.len(), 5);
+        assert_eq!(
+            lines[0],
+            "| Rank | Change | Score | Days 1-2 | Day 01 part 1 | Day 01 part 2 | Name                |"
+        );
+        assert_eq!(
+            lines[2],
+            "|    1 |        |    10 | ★·       |      00:03:20 |      03:40:00 | (anonymous user \
+             #2) |"
+        );
+        assert_eq!(
+            lines[3],
+            "|    2 |        |    10 | ★☆       |      00:05:00 |      00:53:20 | Ada                 |"
+        );
+    }
+}
@@ -0,0 +1,252 @@
+/// Module that renders puzzle descriptions, as converted by
+/// [`html::to_markdown`](crate::template::html::to_markdown), for the terminal: headings and
+/// emphasis are bold, inline code is colored, code blocks are indented and everything else is
+/// wrapped to the width of the terminal.
+use crate::template::{ANSI_BOLD, ANSI_RESET};
+
+const ANSI_CYAN: &str = "\x1b[36m";
+const ANSI_UNDERLINE: &str = "\x1b[4m";
+
+/// Inline styles that are active at a point of a paragraph.
+#[derive(Default)]
+struct Styles {
+    emphasis: bool,
+    code: bool,
+    link: bool,
+}
+
+impl Styles {
+    /// The escape codes that end the current styles and restore the active ones.
+    fn codes(&self) -> String {
+        let mut codes = ANSI_RESET.to_string();
+        if self.emphasis {
+            codes.push_str(ANSI_BOLD);
+        }
+        if self.code {
+            codes.push_str(ANSI_CYAN);
+        }
+        if self.link {
+            codes.push_str(ANSI_UNDERLINE);
+        }
+        codes
+    }
+}
+
+/// Replaces inline markup, i.e. `*emphasis*`, `` `code` `` and `[links](/url)`, with escape codes.
+fn style_inline(text: &str) -> String {
+    let mut styled = String::new();
+    let mut styles = Styles::default();
+    let mut rest = text;
+
+    while let Some(c) = rest.chars().next() {
+        rest = &rest[c.len_utf8()..];
+        match c {
+            '`' => {
+                styles.code = !styles.code;
+                styled.push_str(&styles.codes());
+            }
+            // a literal `*`, e.g. in `2 * 3`, only starts emphasis if it is followed by text
+            // and closed later on.
+            '*' if !styles.code
+                && (styles.emphasis
+                    || (rest.contains('*') && !rest.starts_with(char::is_whitespace))) =>
+            {
+                styles.emphasis = !styles.emphasis;
+                styled.push_str(&styles.codes());
+            }
+            // only brackets that are followed by a target are links.
+            '[' if !styles.code && rest.find("](").is_some_and(|i| !rest[..i].contains('[')) => {
+                styles.link = true;
+                styled.push_str(&styles.codes());
+            }
+            ']' if styles.link && rest.starts_with('(') => {
+                styles.link = false;
+                styled.push_str(&styles.codes());
+                rest = rest.find(')').map_or("", |end| &rest[end + 1..]);
+            }
+            c => styled.push(c),
+        }
+    }
+
+    if styles.emphasis || styles.code || styles.link {
+        styled.push_str(ANSI_RESET);
+    }
+    styled
+}
+
+/// The number of characters a string takes up in the terminal, ignoring escape codes.
+fn visible_width(s: &str) -> usize {
+    let mut width = 0;
+    let mut in_escape = false;
+
+    for c in s.chars() {
+        match c {
+            '\x1b' => in_escape = true,
+            'm' if in_escape => in_escape = false,
+            _ if in_escape => {}
+            _ => width += 1,
+        }
+    }
+
+    width
+}
+
+/// Wraps styled text at spaces so lines don't exceed `width`. The first line starts with
+/// `first_prefix`, the following lines with `prefix`.
+fn wrap(text: &str, width: usize, first_prefix: &str, prefix: &str) -> Vec<String> {
+    let mut lines = vec![];
+    let mut line = first_prefix.to_string();
+    let mut line_width = visible_width(first_prefix);
+    let mut is_empty = true;
+
+    for word in text.split(' ').filter(|word| !word.is_empty()) {
+        let word_width = visible_width(word);
+        if !is_empty && line_width + 1 + word_width > width {
+            lines.push(std::mem::replace(&mut line, prefix.to_string()));
+            line_width = visible_width(prefix);
+            is_empty = true;
+        }
+        if !is_empty {
+            line.push(' ');
+            line_width += 1;
+        }
+        line.push_str(word);
+        line_width += word_width;
+        is_empty = false;
+    }
+
+    lines.push(line);
+    lines
+}
+
+/// Renders markdown for a terminal that is `width` characters wide.
+#[must_use]
+pub fn render(markdown: &str, width: usize) -> String {
+    let mut output: Vec<String> = vec![];
+    let mut paragraph: Vec<&str> = vec![];
+    let mut in_code = false;
+    let mut in_list = false;
+
+    // ends the current paragraph or list, blocks are separated by a blank line.
+    let end_block = |paragraph: &mut Vec<&str>, in_list: &mut bool, output: &mut Vec<String>| {
+        if !paragraph.is_empty() {
+            output.extend(wrap(&style_inline(&paragraph.join(" ")), width, "", ""));
+            output.push(String::new());
+            paragraph.clear();
+        }
+        if *in_list {
+            output.push(String::new());
+            *in_list = false;
+        }
+    };
+
+    for line in markdown.lines() {
+        if line.starts_with("```") {
+            end_block(&mut paragraph, &mut in_list, &mut output);
+            in_code = !in_code;
+            if !in_code {
+                output.push(String::new());
+            }
+        } else if in_code {
+            output.push(format!("    {line}"));
+        } else if let Some(heading) = line.strip_prefix("## ") {
+            end_block(&mut paragraph, &mut in_list, &mut output);
+            output.push(format!("{ANSI_BOLD}{heading}{ANSI_RESET}"));
+            output.push(String::new());
+        } else if let Some(item) = line.strip_prefix("- ") {
+            if !paragraph.is_empty() {
+                end_block(&mut paragraph, &mut in_list, &mut output);
+            }
+            output.extend(wrap(&style_inline(item), width, "  • ", "    "));
+            in_list = true;
+        } else if line.trim().is_empty() {
+            end_block(&mut paragraph, &mut in_list, &mut output);
+        } else {
+            paragraph.push(line.trim());
+        }
+    }
+
+    end_block(&mut paragraph, &mut in_list, &mut output);
+
+    let rendered: Vec<&str> = output.iter().map(|line| line.trim_end()).collect();
+    rendered.join("\n").trim_end().to_string() + "\n"
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{render, style_inline, visible_width, wrap, ANSI_CYAN, ANSI_UNDERLINE};
+    use crate::template::{ANSI_BOLD, ANSI_RESET};
+
+    #[test]
+    fn styles_inline_markup() {
+        assert_eq!(
+            style_inline("a *b*"),
+            format!("a {ANSI_RESET}{ANSI_BOLD}b{ANSI_RESET}")
+        );
+        assert_eq!(
+            style_inline("see [here](/2023/about)."),
+            format!("see {ANSI_RESET}{ANSI_UNDERLINE}here{ANSI_RESET}.")
+        );
+        assert_eq!(
+            style_inline("`a*b` [1, 2]"),
+            format!("{ANSI_RESET}{ANSI_CYAN}a*b{ANSI_RESET} [1, 2]")
+        );
+        assert_eq!(visible_width(&style_inline("*`12`* is [it](/)")), 8);
+
+        assert_eq!(
+            style_inline("2 * 3 is *6*"),
+            format!("2 * 3 is {ANSI_RESET}{ANSI_BOLD}6{ANSI_RESET}")
+        );
+        assert_eq!(style_inline("a lone * star"), "a lone * star");
+        assert_eq!(style_inline("a *lone star"), "a *lone star");
+    }
+
+    #[test]
+    fn wraps_lines() {
+        assert_eq!(
+            wrap("one two three four", 9, "- ", "  "),
+            vec!["- one two", "  three", "  four"]
+        );
+        assert_eq!(
+            wrap("unbreakablewords", 4, "", ""),
+            vec!["unbreakablewords"]
+        );
+    }
+
+    #[test]
+    fn renders_blocks() {
+        let markdown = [
+            "## --- Day 1 ---",
+            "",
+            "For",
+            "example:",
+            "",
+            "```",
+            "1abc2",
+            "  pqr",
+            "```",
+            "",
+            "- one",
+            "- two",
+            "",
+            "Done.",
+        ]
+        .join("\n");
+        let rendered = [
+            &format!("{ANSI_BOLD}--- Day 1 ---{ANSI_RESET}"),
+            "",
+            "For example:",
+            "",
+            "    1abc2",
+            "      pqr",
+            "",
+            "  • one",
+            "  • two",
+            "",
+            "Done.",
+            "",
+        ]
+        .join("\n");
+        assert_eq!(render(&markdown, 80), rendered);
+    }
+}
@@ -1,49 +1,274 @@
-use std::{env, fs};
+use std::{
+    cmp,
+    fmt::Display,
+    fs,
+    io::{self, stderr, Write},
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, Instant},
+};
 
-use crate::Day;
+use crate::{Puzzle, Year};
 
-pub mod aoc_cli;
+pub mod alloc;
+pub mod answers;
+pub mod aoc_client;
+pub mod bench_history;
 pub mod commands;
+pub mod example_tests;
+pub mod examples;
+pub mod html;
+pub mod inputs;
+pub mod leaderboard;
+pub mod markdown;
 pub mod readme_benchmarks;
+pub mod readme_stars;
+pub mod records;
+pub mod registry;
 pub mod runner;
+pub mod stats;
+pub mod submissions;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
+/// An error which can be returned when reading a data file or puzzle input.
+#[derive(Debug)]
+pub struct ReadFileError {
+    /// The file that could not be read, [`None`] for standard input.
+    pub path: Option<PathBuf>,
+    pub source: io::Error,
+}
+
+impl Display for ReadFileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (&self.path, self.source.kind()) {
+            (Some(path), io::ErrorKind::NotFound) => {
+                write!(
+                    f,
+                    "could not open \"{}\": file does not exist",
+                    path.display()
+                )
+            }
+            (Some(path), _) => write!(f, "could not read \"{}\": {}", path.display(), self.source),
+            (None, _) => write!(f, "could not read standard input: {}", self.source),
+        }
+    }
+}
+
+impl std::error::Error for ReadFileError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+/// Returns the directory that holds the data of a year, e.g. `data/2023`. Paths are resolved
+/// against the crate root, so they work regardless of the current working directory.
+#[must_use]
+pub fn get_year_path(year: Year) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("data")
+        .join(year.to_string())
+}
+
+/// Returns the path of the readme that holds the stars and benchmark tables.
+#[must_use]
+pub fn get_readme_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("README.md")
+}
+
+/// Returns the path of a file in a folder of a year's data, e.g. `data/2023/inputs/01.txt`.
+#[must_use]
+pub fn get_data_path(year: Year, folder: &str, file_name: &str) -> PathBuf {
+    get_year_path(year).join(folder).join(file_name)
+}
+
+/// Reads a text file to a string.
+pub fn read_path(path: &Path) -> Result<String, ReadFileError> {
+    fs::read_to_string(path).map_err(|source| ReadFileError {
+        path: Some(path.to_path_buf()),
+        source,
+    })
+}
+
+/// Reads the text file of a puzzle from a `data` folder, e.g. `data/2023/inputs/01.txt`.
+pub fn try_read_file(folder: &str, puzzle: Puzzle) -> Result<String, ReadFileError> {
+    read_path(&get_data_path(
+        puzzle.year,
+        folder,
+        &format!("{}.txt", puzzle.day),
+    ))
+}
+
 /// Helper function that reads a text file to a string.
+///
+/// # Panics
+///
+/// Panics with a description of the problem if the file can't be read.
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+pub fn read_file(folder: &str, puzzle: Puzzle) -> String {
+    try_read_file(folder, puzzle).unwrap_or_else(|e| panic!("{e}"))
 }
 
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
+///
+/// # Panics
+///
+/// Panics with a description of the problem if the file can't be read.
 #[must_use]
-pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd
-        .join("data")
-        .join(folder)
-        .join(format!("{day}-{part}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+pub fn read_file_part(folder: &str, puzzle: Puzzle, part: u8) -> String {
+    read_path(&get_data_path(
+        puzzle.year,
+        folder,
+        &format!("{}-{part}.txt", puzzle.day),
+    ))
+    .unwrap_or_else(|e| panic!("{e}"))
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Formats a number of seconds as e.g. `45s`, `1m 05s`, `2h 03m 05s` or `4d 02h 03m`.
+#[must_use]
+pub fn format_wait(seconds: u64) -> String {
+    let (days, hours, minutes) = (seconds / 86400, seconds / 3600 % 24, seconds / 60 % 60);
+    match seconds {
+        0..=59 => format!("{seconds}s"),
+        60..=3599 => format!("{minutes}m {:02}s", seconds % 60),
+        3600..=86399 => format!("{hours}h {minutes:02}m {:02}s", seconds % 60),
+        _ => format!("{days}d {hours:02}h {minutes:02}m"),
+    }
+}
+
+/// Blocks for `duration`, showing the time that is left before `action`, e.g. "submitting". The
+/// countdown is shown on stderr, so it stays out of `--format json` output.
+pub fn countdown(duration: Duration, action: &str) {
+    let end = Instant::now() + duration;
+
+    while let Some(left) = end.checked_duration_since(Instant::now()) {
+        if left.is_zero() {
+            break;
+        }
+        eprint!(
+            "\rWaiting {} before {action}... ",
+            format_wait(left.as_secs() + 1)
+        );
+        let _ = stderr().flush();
+        thread::sleep(cmp::min(left, Duration::from_secs(1)));
+    }
+
+    eprint!("\r{:60}\r", "");
+}
+
+/// Lays out rows as a markdown table with padded columns, the first row is the header. Columns
+/// are aligned by their markdown alignment, e.g. `---:` for right and `:---` for left.
+#[must_use]
+pub fn table(rows: &[Vec<String>], alignments: &[&str]) -> Vec<String> {
+    let widths: Vec<usize> = (0..alignments.len())
+        .map(|i| {
+            rows.iter()
+                .map(|row| row[i].chars().count())
+                .max()
+                .unwrap_or(0)
+                .max(alignments[i].len())
+        })
+        .collect();
+
+    let line = |cells: Vec<String>| format!("| {} |", cells.join(" | "));
+    let pad = |cell: &str, i: usize| {
+        let padding = " ".repeat(widths[i] - cell.chars().count());
+        if alignments[i].starts_with("---") {
+            format!("{padding}{cell}")
+        } else {
+            format!("{cell}{padding}")
+        }
+    };
+
+    let mut lines = vec![line(
+        rows[0].iter().enumerate().map(|(i, c)| pad(c, i)).collect(),
+    )];
+    lines.push(line(
+        alignments
+            .iter()
+            .enumerate()
+            .map(|(i, a)| a.replace("---", &"-".repeat(widths[i] - a.len() + 3)))
+            .collect(),
+    ));
+    lines.extend(
+        rows[1..]
+            .iter()
+            .map(|row| line(row.iter().enumerate().map(|(i, c)| pad(c, i)).collect())),
+    );
+    lines
+}
+
+/// Creates the constants `YEAR`, `DAY` and `PUZZLE`, registers the solution and sets up the input
+/// and runner for each part.
+///
+/// Optionally takes a `parse` function as third argument. Its output is computed once, timed
+/// separately and then passed to both parts by reference:
+///
+/// ```ignore
+/// advent_of_code::solution!(2023, 8, parse);
+///
+/// fn parse(input: &str) -> Network<'_> { ... }
+/// pub fn part_one(network: &Network) -> Option<u32> { ... }
+/// pub fn part_two(network: &Network) -> Option<u64> { ... }
+/// ```
 #[macro_export]
 macro_rules! solution {
-    ($day:expr) => {
+    ($year:expr, $day:expr) => {
+        advent_of_code::solution!(@register $year, $day, |input, options| {
+            vec![
+                run_part(part_one, input, PUZZLE, 1, options),
+                run_part(part_two, input, PUZZLE, 2, options),
+            ]
+        });
+    };
+    ($year:expr, $day:expr, $parse:expr) => {
+        advent_of_code::solution!(@register $year, $day, |input, options| {
+            let (parsed, parse_record) = run_parse($parse, input, PUZZLE, options);
+            match parsed {
+                Some(parsed) => vec![
+                    parse_record,
+                    run_part(part_one, &parsed, PUZZLE, 1, options),
+                    run_part(part_two, &parsed, PUZZLE, 2, options),
+                ],
+                // the parts can't run without their input.
+                None => vec![parse_record],
+            }
+        });
+    };
+    (@register $year:expr, $day:expr, |$input:ident, $options:ident| $run:block) => {
+        /// The year of the current puzzle.
+        const YEAR: advent_of_code::Year = advent_of_code::year!($year);
         /// The current day.
         const DAY: advent_of_code::Day = advent_of_code::day!($day);
+        /// The current puzzle.
+        const PUZZLE: advent_of_code::Puzzle = advent_of_code::Puzzle::new(YEAR, DAY);
+
+        /// Runs this day's parts, used by the solution registry.
+        pub struct Solution;
+
+        impl advent_of_code::template::registry::Solution for Solution {
+            fn puzzle(&self) -> advent_of_code::Puzzle {
+                PUZZLE
+            }
+
+            fn run(
+                &self,
+                $input: &str,
+                $options: &advent_of_code::template::runner::RunOptions,
+            ) -> Vec<advent_of_code::template::records::PartRecord> {
+                use advent_of_code::template::runner::*;
+                $run
+            }
+        }
 
         fn main() {
             use advent_of_code::template::runner::*;
-            let input = advent_of_code::template::read_file("inputs", DAY);
-            run_part(part_one, &input, DAY, 1);
-            run_part(part_two, &input, DAY, 2);
+            if let Err(e) = run_day(&Solution, &RunOptions::from_args()) {
+                eprintln!("{e}");
+                std::process::exit(1);
+            }
         }
     };
 }
@@ -1,10 +1,18 @@
 /// Module that updates the readme me with timing information.
-/// The approach taken is similar to how `aoc-readme-stars` handles this.
+/// The approach taken is similar to how `aoc-readme-stars` handles this. Every year has its own
+/// table, delimited by markers like `<!--- benchmarking table 2023 --->`.
 use std::{fs, io};
 
-use crate::Day;
+use crate::{
+    template::{alloc::AllocStats, get_readme_path, stats::Stats},
+    Puzzle, Year,
+};
 
-static MARKER: &str = "<!--- benchmarking table --->";
+static MARKER_PREFIX: &str = "<!--- benchmarking table";
+
+fn marker(year: Year) -> String {
+    format!("{MARKER_PREFIX} {year} --->")
+}
 
 #[derive(Debug)]
 pub enum Error {
@@ -20,9 +28,14 @@ impl From<std::io::Error> for Error {
 
 #[derive(Clone)]
 pub struct Timings {
-    pub day: Day,
-    pub part_1: Option<String>,
-    pub part_2: Option<String>,
+    pub puzzle: Puzzle,
+    pub parse: Option<Stats>,
+    pub part_1: Option<Stats>,
+    pub part_2: Option<Stats>,
+    /// Heap usage of the parse step and both parts, if allocations were counted.
+    pub parse_allocs: Option<AllocStats>,
+    pub part_1_allocs: Option<AllocStats>,
+    pub part_2_allocs: Option<AllocStats>,
     pub total_nanos: f64,
 }
 
@@ -32,12 +45,13 @@ pub struct TablePosition {
 }
 
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_bin(puzzle: Puzzle) -> String {
+    format!("./src/bin/{puzzle}.rs")
 }
 
-fn locate_table(readme: &str) -> Result<TablePosition, Error> {
-    let matches: Vec<_> = readme.match_indices(MARKER).collect();
+fn locate_table(readme: &str, year: Year) -> Result<TablePosition, Error> {
+    let marker = marker(year);
+    let matches: Vec<_> = readme.match_indices(&marker).collect();
 
     if matches.len() > 2 {
         return Err(Error::Parser(
@@ -58,73 +72,143 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Vec<Timings>, total_millis: f64) -> String {
-    let header = format!("{prefix} Benchmarks");
+/// Formats the stats of a part as median ± standard deviation.
+fn format_stats(stats: &Stats) -> String {
+    if stats.samples == 1 {
+        format!("{:.1?}", stats.median)
+    } else {
+        format!("{:.1?} ± {:.1?}", stats.median, stats.std_dev)
+    }
+}
+
+fn format_cell(stats: Option<Stats>, allocs: Option<AllocStats>) -> String {
+    let time = stats.map_or_else(|| "-".into(), |s| format_stats(&s));
+    match allocs {
+        Some(allocs) => format!("`{time}`<br>{allocs}"),
+        None => format!("`{time}`"),
+    }
+}
+
+/// Finds the end of the last table of any year, where the table of a new year goes.
+fn locate_last_table_end(readme: &str) -> Option<usize> {
+    let start = readme.rfind(MARKER_PREFIX)?;
+    let end = readme[start..].find("--->")?;
+    Some(start + end + "--->".len())
+}
+
+fn construct_table(prefix: &str, year: Year, timings: Vec<Timings>, total_millis: f64) -> String {
+    let header = format!("{prefix} {year} Benchmarks");
 
     let mut lines: Vec<String> = vec![
-        MARKER.into(),
+        marker(year),
         header,
         String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
+        "| Day | Parse | Part 1 | Part 2 |".into(),
+        "| :---: | :---: | :---: | :---:  |".into(),
     ];
 
     for timing in timings {
-        let path = get_path_for_bin(timing.day);
+        let path = get_path_for_bin(timing.puzzle);
         lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
-            timing.day.into_inner(),
+            "| [Day {}]({}) | {} | {} | {} |",
+            timing.puzzle.day.into_inner(),
             path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
+            format_cell(timing.parse, timing.parse_allocs),
+            format_cell(timing.part_1, timing.part_1_allocs),
+            format_cell(timing.part_2, timing.part_2_allocs),
         ));
     }
 
     lines.push(String::new());
     lines.push(format!("**Total: {total_millis:.2}ms**"));
-    lines.push(MARKER.into());
+    lines.push(marker(year));
 
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
-    let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
+fn update_content(
+    s: &mut String,
+    year: Year,
+    timings: Vec<Timings>,
+    total_millis: f64,
+) -> Result<(), Error> {
+    let table = construct_table("##", year, timings, total_millis);
+
+    // the first run of another year adds its table below the existing ones.
+    if !s.contains(&marker(year)) {
+        if let Some(end) = locate_last_table_end(s) {
+            s.insert_str(end, &format!("\n\n{table}"));
+            return Ok(());
+        }
+    }
+
+    let positions = locate_table(s, year)?;
     s.replace_range(positions.pos_start..positions.pos_end, &table);
+
     Ok(())
 }
 
-pub fn update(timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
-    let path = "README.md";
-    let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
-    update_content(&mut readme, timings, total_millis)?;
+pub fn update(year: Year, timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
+    let path = get_readme_path();
+    let mut readme = String::from_utf8_lossy(&fs::read(&path)?).to_string();
+    update_content(&mut readme, year, timings, total_millis)?;
     fs::write(path, &readme)?;
     Ok(())
 }
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{update_content, Timings, MARKER};
-    use crate::day;
+    use std::time::Duration;
+
+    use super::{update_content, Timings};
+    use crate::{
+        day,
+        template::{alloc::AllocStats, stats::Stats},
+        year, Puzzle, Year,
+    };
+
+    const MARKER: &str = "<!--- benchmarking table 2023 --->";
+    const YEAR: Year = year!(2023);
+
+    fn mock_stats(median_millis: u64, std_dev_millis: u64) -> Option<Stats> {
+        Some(Stats {
+            median: Duration::from_millis(median_millis),
+            std_dev: Duration::from_millis(std_dev_millis),
+            samples: 100,
+            ..Stats::single(Duration::from_millis(median_millis))
+        })
+    }
 
     fn get_mock_timings() -> Vec<Timings> {
         vec![
             Timings {
-                day: day!(1),
-                part_1: Some("10ms".into()),
-                part_2: Some("20ms".into()),
+                puzzle: Puzzle::new(YEAR, day!(1)),
+                parse: None,
+                part_1: mock_stats(10, 1),
+                part_2: mock_stats(20, 2),
+                parse_allocs: None,
+                part_1_allocs: None,
+                part_2_allocs: None,
                 total_nanos: 3e+10,
             },
             Timings {
-                day: day!(2),
-                part_1: Some("30ms".into()),
-                part_2: Some("40ms".into()),
+                puzzle: Puzzle::new(YEAR, day!(2)),
+                parse: mock_stats(5, 1),
+                part_1: mock_stats(30, 3),
+                part_2: mock_stats(40, 4),
+                parse_allocs: None,
+                part_1_allocs: None,
+                part_2_allocs: None,
                 total_nanos: 7e+10,
             },
             Timings {
-                day: day!(4),
-                part_1: Some("40ms".into()),
-                part_2: Some("50ms".into()),
+                puzzle: Puzzle::new(YEAR, day!(4)),
+                parse: None,
+                part_1: mock_stats(40, 4),
+                part_2: Some(Stats::single(Duration::from_millis(50))),
+                parse_allocs: None,
+                part_1_allocs: None,
+                part_2_allocs: None,
                 total_nanos: 9e+10,
             },
         ]
@@ -134,53 +218,94 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, YEAR, get_mock_timings(), 190.0).unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
         let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, YEAR, get_mock_timings(), 190.0).unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        assert_eq!(s.contains("## Benchmarks"), true);
+        update_content(&mut s, YEAR, get_mock_timings(), 190.0).unwrap();
+        assert_eq!(s.contains("## 2023 Benchmarks"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, YEAR, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, YEAR, get_mock_timings(), 190.0).unwrap();
         assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
-        assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
+        assert_eq!(
+            s.matches("## 2023 Benchmarks").collect::<Vec<&str>>().len(),
+            1
+        );
+    }
+
+    #[test]
+    fn adds_tables_for_other_years() {
+        let mut s = format!("foo\n{}\n{}\nbaz", MARKER, MARKER);
+        let timings = get_mock_timings()
+            .into_iter()
+            .map(|timings| Timings {
+                puzzle: Puzzle::new(year!(2022), timings.puzzle.day),
+                ..timings
+            })
+            .collect();
+        update_content(&mut s, year!(2022), timings, 190.0).unwrap();
+        assert_eq!(s.starts_with(&format!("foo\n{MARKER}\n{MARKER}\n\n")), true);
+        assert_eq!(s.contains("## 2022 Benchmarks"), true);
+        assert_eq!(s.contains("[Day 1](./src/bin/2022-01.rs)"), true);
+        assert_eq!(s.ends_with("<!--- benchmarking table 2022 --->\nbaz"), true);
     }
 
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, YEAR, get_mock_timings(), 190.0).unwrap();
         let expected = [
             "foo",
             "bar",
-            "<!--- benchmarking table --->",
-            "## Benchmarks",
+            "<!--- benchmarking table 2023 --->",
+            "## 2023 Benchmarks",
             "",
-            "| Day | Part 1 | Part 2 |",
-            "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| Day | Parse | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: | :---:  |",
+            "| [Day 1](./src/bin/2023-01.rs) | `-` | `10.0ms ± 1.0ms` | `20.0ms ± 2.0ms` |",
+            "| [Day 2](./src/bin/2023-02.rs) | `5.0ms ± 1.0ms` | `30.0ms ± 3.0ms` | `40.0ms ± \
+             4.0ms` |",
+            "| [Day 4](./src/bin/2023-04.rs) | `-` | `40.0ms ± 4.0ms` | `50.0ms` |",
             "",
             "**Total: 190.00ms**",
-            "<!--- benchmarking table --->",
+            "<!--- benchmarking table 2023 --->",
             "baz",
         ]
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn format_alloc_stats() {
+        let mut timings = get_mock_timings();
+        timings[0].part_1_allocs = Some(AllocStats {
+            count: 3,
+            bytes: 2048,
+            peak_bytes: 1024,
+        });
+
+        let mut s = format!("{}{}", MARKER, MARKER);
+        update_content(&mut s, YEAR, timings, 190.0).unwrap();
+        assert_eq!(
+            s.contains(
+                "| [Day 1](./src/bin/2023-01.rs) | `-` | `10.0ms ± 1.0ms`<br>3 allocs, 2.0 KiB \
+                 total, 1.0 KiB peak | `20.0ms ± 2.0ms` |"
+            ),
+            true
+        );
+    }
 }
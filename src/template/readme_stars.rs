@@ -0,0 +1,262 @@
+/// Module that updates the readme with the stars earned per day, between the
+/// `<!--- advent_readme_stars table --->` markers used by the `advent-readme-stars` workflow, but
+/// without a session cookie or CI run.
+///
+/// A part counts as solved if its answer was recorded in `data/<year>/answers/NN.toml` or an
+/// answer was accepted according to `data/<year>/submissions.jsonl`. Stars that are already in the
+/// table are kept, as stars can't be lost and the local data may be incomplete (e.g. on a fresh
+/// clone, or for parts solved before answers were recorded).
+use std::{collections::BTreeMap, fmt::Display, fs, io, path::Path};
+
+use crate::{
+    all_days,
+    template::{answers, aoc_client::Outcome, get_readme_path, submissions},
+    Day, Puzzle, Year,
+};
+
+static MARKER: &str = "<!--- advent_readme_stars table --->";
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "could not update stars: {e}"),
+            Error::IO(e) => write!(f, "could not update stars: {e}"),
+        }
+    }
+}
+
+/// Which parts of each day are solved, per year.
+pub type Stars = BTreeMap<Year, BTreeMap<Day, [bool; 2]>>;
+
+/// Collects the stars of a year from recorded answers and accepted submissions.
+pub fn collect(year: Year) -> Result<BTreeMap<Day, [bool; 2]>, Error> {
+    let mut stars: BTreeMap<Day, [bool; 2]> = BTreeMap::new();
+
+    for day in all_days() {
+        let answers = answers::read(Puzzle::new(year, day))
+            .map_err(|e| Error::Parser(format!("day {day}: {e}")))?;
+        for part in 1..=2 {
+            if answers.get(part).is_some() {
+                stars.entry(day).or_default()[usize::from(part - 1)] = true;
+            }
+        }
+    }
+
+    let ledger = submissions::read(year).map_err(|e| Error::Parser(e.to_string()))?;
+    for entry in ledger.entries {
+        if entry.verdict == Outcome::Correct && (1..=2).contains(&entry.part) {
+            stars.entry(entry.day).or_default()[usize::from(entry.part - 1)] = true;
+        }
+    }
+
+    Ok(stars)
+}
+
+/// The years that have a folder in `data`.
+fn data_years() -> Vec<Year> {
+    let Ok(entries) = fs::read_dir(Path::new(env!("CARGO_MANIFEST_DIR")).join("data")) else {
+        return vec![];
+    };
+
+    let mut years: Vec<Year> = entries
+        .filter_map(Result::ok)
+        .filter_map(|entry| entry.file_name().to_str()?.parse().ok())
+        .collect();
+    years.sort();
+    years
+}
+
+fn locate_table(readme: &str) -> Result<(usize, usize), Error> {
+    let matches: Vec<_> = readme.match_indices(MARKER).collect();
+
+    match matches.as_slice() {
+        [start, end] => Ok((start.0, end.0 + end.1.len())),
+        [] | [_] => Err(Error::Parser("could not find the table markers.".into())),
+        _ => Err(Error::Parser(
+            "too many occurences of marker in README.".into(),
+        )),
+    }
+}
+
+/// Reads the stars of the tables of a section, as written by [`construct_table`] or the
+/// `advent-readme-stars` workflow.
+fn parse_table(section: &str) -> Stars {
+    let mut stars = Stars::new();
+    let mut year = None;
+
+    for line in section.lines() {
+        if let Some(heading) = line.strip_prefix("## ") {
+            year = heading
+                .split_whitespace()
+                .next()
+                .and_then(|y| y.parse().ok());
+            continue;
+        }
+
+        let cells: Vec<&str> = line.split('|').map(str::trim).collect();
+        let (Some(year), [_, day, part_one, part_two, ..]) = (year, cells.as_slice()) else {
+            continue;
+        };
+        let Some(day) = day
+            .strip_prefix("[Day ")
+            .and_then(|d| d.split(']').next())
+            .and_then(|d| d.parse::<u8>().ok())
+            .and_then(Day::new)
+        else {
+            continue;
+        };
+
+        stars
+            .entry(year)
+            .or_default()
+            .insert(day, [part_one.contains('⭐'), part_two.contains('⭐')]);
+    }
+
+    stars
+}
+
+fn construct_table(stars: &Stars) -> String {
+    let mut lines: Vec<String> = vec![MARKER.into()];
+
+    for (year, days) in stars.iter().filter(|(_, days)| !days.is_empty()) {
+        if lines.len() > 1 {
+            lines.push(String::new());
+        }
+        lines.push(format!("## {year} Results"));
+        lines.push(String::new());
+        lines.push("| Day | Part 1 | Part 2 |".into());
+        lines.push("| :---: | :---: | :---: |".into());
+
+        for (day, parts) in days {
+            let day = day.into_inner();
+            let star = |solved: bool| if solved { "⭐" } else { " " };
+            lines.push(format!(
+                "| [Day {day}](https://adventofcode.com/{year}/day/{day}) | {} | {} |",
+                star(parts[0]),
+                star(parts[1])
+            ));
+        }
+    }
+
+    lines.push(MARKER.into());
+    lines.join("\n")
+}
+
+fn update_content(s: &mut String, local: Stars) -> Result<(), Error> {
+    let (start, end) = locate_table(s)?;
+    let mut stars = parse_table(&s[start..end]);
+
+    for (year, days) in local {
+        let known = stars.entry(year).or_default();
+        for (day, parts) in days {
+            let known = known.entry(day).or_default();
+            known[0] |= parts[0];
+            known[1] |= parts[1];
+        }
+    }
+
+    s.replace_range(start..end, &construct_table(&stars));
+    Ok(())
+}
+
+/// Adds the stars of every year in `data` to the readme.
+pub fn update() -> Result<(), Error> {
+    let local = data_years()
+        .into_iter()
+        .map(|year| Ok((year, collect(year)?)))
+        .collect::<Result<Stars, Error>>()?;
+
+    let path = get_readme_path();
+    let mut readme = String::from_utf8_lossy(&fs::read(&path)?).to_string();
+    update_content(&mut readme, local)?;
+    fs::write(path, &readme)?;
+    Ok(())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::{parse_table, update_content, Stars, MARKER};
+    use crate::{day, year};
+
+    fn readme() -> String {
+        [
+            "# readme",
+            MARKER,
+            "## 2023 Results",
+            "",
+            "| Day | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: |",
+            "| [Day 1](https://adventofcode.com/2023/day/1) | ⭐ | ⭐ |",
+            "| [Day 2](https://adventofcode.com/2023/day/2) | ⭐ |   |",
+            MARKER,
+            "",
+            "foo",
+        ]
+        .join("\n")
+    }
+
+    #[test]
+    fn parses_existing_tables() {
+        let stars = parse_table(&readme());
+        assert_eq!(stars[&year!(2023)][&day!(1)], [true, true]);
+        assert_eq!(stars[&year!(2023)][&day!(2)], [true, false]);
+        assert_eq!(stars[&year!(2023)].len(), 2);
+    }
+
+    #[test]
+    fn merges_local_stars() {
+        let mut local = Stars::new();
+        local.insert(
+            year!(2023),
+            BTreeMap::from([(day!(2), [false, true]), (day!(10), [true, false])]),
+        );
+        local.insert(year!(2022), BTreeMap::from([(day!(1), [true, true])]));
+        local.insert(year!(2021), BTreeMap::new());
+
+        let mut s = readme();
+        update_content(&mut s, local).unwrap();
+
+        let expected = [
+            "# readme",
+            MARKER,
+            "## 2022 Results",
+            "",
+            "| Day | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: |",
+            "| [Day 1](https://adventofcode.com/2022/day/1) | ⭐ | ⭐ |",
+            "",
+            "## 2023 Results",
+            "",
+            "| Day | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: |",
+            "| [Day 1](https://adventofcode.com/2023/day/1) | ⭐ | ⭐ |",
+            "| [Day 2](https://adventofcode.com/2023/day/2) | ⭐ | ⭐ |",
+            "| [Day 10](https://adventofcode.com/2023/day/10) | ⭐ |   |",
+            MARKER,
+            "",
+            "foo",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
+
+    #[test]
+    fn errors_without_markers() {
+        let mut s = "# readme".to_string();
+        assert_eq!(update_content(&mut s, Stars::new()).is_err(), true);
+    }
+}
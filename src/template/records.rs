@@ -0,0 +1,408 @@
+/// Structured, machine-readable records of solution runs.
+/// These are emitted as one JSON object per line when running with `--format json`.
+use std::{collections::HashMap, fmt::Display, str::FromStr, time::Duration};
+
+use tinyjson::JsonValue;
+
+use crate::{
+    template::{alloc::AllocStats, answers::Verdict, stats::Stats},
+    Day, Year,
+};
+
+/// Output format of the `solve` and `all` commands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputFormat {
+    #[default]
+    Human,
+    Json,
+}
+
+impl FromStr for OutputFormat {
+    type Err = OutputFormatFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "human" => Ok(Self::Human),
+            "json" => Ok(Self::Json),
+            _ => Err(OutputFormatFromStrError),
+        }
+    }
+}
+
+impl Display for OutputFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Human => f.write_str("human"),
+            Self::Json => f.write_str("json"),
+        }
+    }
+}
+
+/// An error which can be returned when parsing an [`OutputFormat`].
+#[derive(Debug)]
+pub struct OutputFormatFromStrError;
+
+impl std::error::Error for OutputFormatFromStrError {}
+
+impl Display for OutputFormatFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting an output format of either \"human\" or \"json\"")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Outcome of running a solution part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The part returned an answer.
+    Solved,
+    /// The part returned [`None`].
+    Unsolved,
+    /// The part panicked, see [`PartRecord::error`] for the message.
+    Panicked,
+    /// The part returned an error, see [`PartRecord::error`] for the message.
+    Errored,
+}
+
+impl Status {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Solved => "solved",
+            Self::Unsolved => "unsolved",
+            Self::Panicked => "panicked",
+            Self::Errored => "errored",
+        }
+    }
+
+    fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "solved" => Some(Self::Solved),
+            "unsolved" => Some(Self::Unsolved),
+            "panicked" => Some(Self::Panicked),
+            "errored" => Some(Self::Errored),
+            _ => None,
+        }
+    }
+}
+
+/// The `part` of records that time the shared `parse` function of a solution.
+pub const PARSE_STEP: u8 = 0;
+
+/// The result of running a single part of a solution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartRecord {
+    pub year: Year,
+    pub day: Day,
+    /// The part number, or [`PARSE_STEP`] for the `parse` function.
+    pub part: u8,
+    pub answer: Option<String>,
+    pub status: Status,
+    pub stats: Stats,
+    /// The accepted answer for this part, if known.
+    pub expected: Option<String>,
+    /// Why the part failed, if it did.
+    pub error: Option<String>,
+    /// Heap usage of a single run, if allocations were counted.
+    pub allocs: Option<AllocStats>,
+}
+
+impl PartRecord {
+    /// How the answer compares to the accepted answer.
+    #[must_use]
+    pub fn verdict(&self) -> Verdict {
+        Verdict::of(self.answer.as_deref(), self.expected.as_deref())
+    }
+
+    #[must_use]
+    pub fn to_json(&self) -> JsonValue {
+        let mut object: HashMap<String, JsonValue> = HashMap::new();
+        object.insert("year".into(), number(self.year.into_inner()));
+        object.insert("day".into(), number(self.day.into_inner()));
+        object.insert("part".into(), number(self.part));
+        object.insert(
+            "answer".into(),
+            self.answer
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+        object.insert("status".into(), self.status.as_str().to_string().into());
+        object.insert("stats".into(), stats_to_json(&self.stats));
+        object.insert(
+            "expected".into(),
+            self.expected
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+        object.insert(
+            "error".into(),
+            self.error
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+        object.insert(
+            "allocs".into(),
+            self.allocs.as_ref().map_or(JsonValue::Null, allocs_to_json),
+        );
+        if self.part != PARSE_STEP {
+            object.insert(
+                "verdict".into(),
+                verdict_str(self.verdict()).to_string().into(),
+            );
+        }
+        object.into()
+    }
+
+    /// Serializes the record to a single line of JSON.
+    #[must_use]
+    pub fn to_json_line(&self) -> String {
+        // NOTE: the record only contains strings and finite numbers, which always serialize.
+        self.to_json().stringify().unwrap()
+    }
+
+    /// Reads a record back from its JSON representation, returns [`None`] if the value
+    /// is not a well-formed record.
+    #[must_use]
+    pub fn from_json(value: &JsonValue) -> Option<Self> {
+        let object: &HashMap<_, _> = value.get()?;
+
+        let answer = optional_string(object.get("answer")?)?;
+        let expected = optional_string(object.get("expected")?)?;
+        let error = match object.get("error") {
+            Some(value) => optional_string(value)?,
+            None => None,
+        };
+        let allocs = match object.get("allocs") {
+            Some(JsonValue::Null) | None => None,
+            Some(value) => Some(allocs_from_json(value)?),
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Some(Self {
+            year: Year::new(*object.get("year")?.get::<f64>()? as u16)?,
+            day: Day::new(*object.get("day")?.get::<f64>()? as u8)?,
+            part: *object.get("part")?.get::<f64>()? as u8,
+            answer,
+            status: Status::from_str(object.get("status")?.get::<String>()?)?,
+            stats: stats_from_json(object.get("stats")?)?,
+            expected,
+            error,
+            allocs,
+        })
+    }
+
+    /// Parses a single line of output, returns [`None`] if it is not a record.
+    #[must_use]
+    pub fn from_json_line(line: &str) -> Option<Self> {
+        Self::from_json(&line.parse().ok()?)
+    }
+}
+
+#[cfg(feature = "test_lib")]
+impl PartRecord {
+    /// A record of a part of day 8 of 2023 for tests, solved if it has an answer. Set the other
+    /// fields with struct update syntax.
+    #[must_use]
+    pub(crate) fn mock(part: u8, answer: Option<&str>) -> Self {
+        Self {
+            year: crate::year!(2023),
+            day: crate::day!(8),
+            part,
+            answer: answer.map(Into::into),
+            status: if answer.is_some() {
+                Status::Solved
+            } else {
+                Status::Unsolved
+            },
+            stats: Stats::single(Duration::from_micros(12)),
+            expected: None,
+            error: None,
+            allocs: None,
+        }
+    }
+}
+
+fn verdict_str(verdict: Verdict) -> &'static str {
+    match verdict {
+        Verdict::Matches => "matches",
+        Verdict::Differs => "differs",
+        Verdict::Unknown => "unknown",
+    }
+}
+
+/// Reads a value that is either a string or `null`.
+fn optional_string(value: &JsonValue) -> Option<Option<String>> {
+    match value {
+        JsonValue::Null => Some(None),
+        JsonValue::String(s) => Some(Some(s.clone())),
+        _ => None,
+    }
+}
+
+fn number(n: impl Into<f64>) -> JsonValue {
+    JsonValue::Number(n.into())
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn nanos(duration: Duration) -> JsonValue {
+    number(duration.as_nanos() as f64)
+}
+
+fn stats_to_json(stats: &Stats) -> JsonValue {
+    let mut object: HashMap<String, JsonValue> = HashMap::new();
+    object.insert("min_ns".into(), nanos(stats.min));
+    object.insert("median_ns".into(), nanos(stats.median));
+    object.insert("p95_ns".into(), nanos(stats.p95));
+    object.insert("max_ns".into(), nanos(stats.max));
+    object.insert("mean_ns".into(), nanos(stats.mean));
+    object.insert("std_dev_ns".into(), nanos(stats.std_dev));
+    #[allow(clippy::cast_precision_loss)]
+    object.insert("samples".into(), number(stats.samples as f64));
+    object.into()
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn stats_from_json(value: &JsonValue) -> Option<Stats> {
+    let object: &HashMap<_, _> = value.get()?;
+    let duration = |key: &str| -> Option<Duration> {
+        Some(Duration::from_nanos(*object.get(key)?.get::<f64>()? as u64))
+    };
+
+    Some(Stats {
+        min: duration("min_ns")?,
+        median: duration("median_ns")?,
+        p95: duration("p95_ns")?,
+        max: duration("max_ns")?,
+        mean: duration("mean_ns")?,
+        std_dev: duration("std_dev_ns")?,
+        samples: *object.get("samples")?.get::<f64>()? as u128,
+    })
+}
+
+fn allocs_to_json(allocs: &AllocStats) -> JsonValue {
+    let mut object: HashMap<String, JsonValue> = HashMap::new();
+    #[allow(clippy::cast_precision_loss)]
+    {
+        object.insert("count".into(), number(allocs.count as f64));
+        object.insert("bytes".into(), number(allocs.bytes as f64));
+        object.insert("peak_bytes".into(), number(allocs.peak_bytes as f64));
+    }
+    object.into()
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn allocs_from_json(value: &JsonValue) -> Option<AllocStats> {
+    let object: &HashMap<_, _> = value.get()?;
+    let count = |key: &str| -> Option<u64> { Some(*object.get(key)?.get::<f64>()? as u64) };
+
+    Some(AllocStats {
+        count: count("count")?,
+        bytes: count("bytes")?,
+        peak_bytes: count("peak_bytes")?,
+    })
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{PartRecord, Status};
+    use crate::template::{alloc::AllocStats, stats::Stats};
+
+    fn get_mock_record() -> PartRecord {
+        PartRecord {
+            stats: Stats {
+                std_dev: Duration::from_nanos(12),
+                samples: 100_000,
+                ..Stats::single(Duration::from_nanos(7413))
+            },
+            expected: Some("samples)\nmulti-line".into()),
+            ..PartRecord::mock(2, Some("samples)\nmulti-line"))
+        }
+    }
+
+    #[test]
+    fn roundtrips_records() {
+        let record = get_mock_record();
+        let line = record.to_json_line();
+        assert_eq!(line.contains('\n'), false);
+        assert_eq!(line.contains("\"year\":2023"), true);
+        assert_eq!(PartRecord::from_json_line(&line), Some(record));
+    }
+
+    #[test]
+    fn roundtrips_unsolved_records() {
+        let record = PartRecord {
+            answer: None,
+            status: Status::Unsolved,
+            expected: None,
+            ..get_mock_record()
+        };
+        assert_eq!(
+            PartRecord::from_json_line(&record.to_json_line()),
+            Some(record)
+        );
+    }
+
+    #[test]
+    fn roundtrips_panicked_records() {
+        let record = PartRecord {
+            answer: None,
+            status: Status::Panicked,
+            error: Some("not L or R".into()),
+            ..get_mock_record()
+        };
+        assert_eq!(
+            PartRecord::from_json_line(&record.to_json_line()),
+            Some(record)
+        );
+    }
+
+    #[test]
+    fn roundtrips_errored_records() {
+        let record = PartRecord {
+            answer: None,
+            status: Status::Errored,
+            error: Some("invalid card: X".into()),
+            ..get_mock_record()
+        };
+        assert_eq!(
+            PartRecord::from_json_line(&record.to_json_line()),
+            Some(record)
+        );
+    }
+
+    #[test]
+    fn roundtrips_alloc_stats() {
+        let record = PartRecord {
+            allocs: Some(AllocStats {
+                count: 12,
+                bytes: 4096,
+                peak_bytes: 1024,
+            }),
+            ..get_mock_record()
+        };
+        assert_eq!(
+            PartRecord::from_json_line(&record.to_json_line()),
+            Some(record)
+        );
+    }
+
+    #[test]
+    fn includes_verdict() {
+        let record = PartRecord {
+            expected: Some("other".into()),
+            ..get_mock_record()
+        };
+        assert_eq!(
+            record.to_json_line().contains("\"verdict\":\"differs\""),
+            true
+        );
+    }
+
+    #[test]
+    fn ignores_other_output() {
+        assert_eq!(PartRecord::from_json_line("Part 1: 42 (1.0ms)"), None);
+        assert_eq!(PartRecord::from_json_line("{\"day\": 1}"), None);
+    }
+}
@@ -0,0 +1,49 @@
+/// Registry of scaffolded solutions that can be run in-process.
+/// Every day implements [`Solution`] through the `solution!` macro. The main binary collects all
+/// days of all years found in `src/bin` into a [`Registry`] at build time, see `build.rs`.
+use crate::{
+    template::{records::PartRecord, runner::RunOptions},
+    Puzzle, Year,
+};
+
+/// A scaffolded day.
+pub trait Solution: Sync {
+    /// The puzzle this solution belongs to.
+    fn puzzle(&self) -> Puzzle;
+
+    /// Runs both parts against `input`, reporting them according to `options`.
+    fn run(&self, input: &str, options: &RunOptions) -> Vec<PartRecord>;
+}
+
+/// A set of solutions, ordered by year and day.
+pub struct Registry {
+    solutions: Vec<&'static dyn Solution>,
+}
+
+impl Registry {
+    #[must_use]
+    pub fn new(mut solutions: Vec<&'static dyn Solution>) -> Self {
+        solutions.sort_by_key(|solution| solution.puzzle());
+        Self { solutions }
+    }
+
+    /// Returns the solution for `puzzle`, or [`None`] if the day has not been scaffolded.
+    #[must_use]
+    pub fn get(&self, puzzle: Puzzle) -> Option<&'static dyn Solution> {
+        self.solutions
+            .iter()
+            .find(|solution| solution.puzzle() == puzzle)
+            .copied()
+    }
+
+    /// An iterator over all registered solutions, in order of years and days.
+    pub fn iter(&self) -> impl Iterator<Item = &'static dyn Solution> + '_ {
+        self.solutions.iter().copied()
+    }
+
+    /// An iterator over the registered solutions of `year`, in order of days.
+    pub fn year(&self, year: Year) -> impl Iterator<Item = &'static dyn Solution> + '_ {
+        self.iter()
+            .filter(move |solution| solution.puzzle().year == year)
+    }
+}
@@ -1,68 +1,432 @@
 use std::{
-    cmp, env,
+    any::Any,
+    cmp,
+    convert::Infallible,
     fmt::Display,
-    io::{stdout, Write},
+    io::{self, stdout, Read, Write},
+    panic::{self, AssertUnwindSafe},
+    path::PathBuf,
     process,
-    process::Output,
+    str::FromStr,
     time::{Duration, Instant},
 };
 
 use super::ANSI_BOLD;
 /// Encapsulates code that interacts with solution functions.
-use crate::template::{aoc_cli, ANSI_ITALIC, ANSI_RESET};
-use crate::Day;
+use crate::template::{
+    self,
+    alloc::{self, AllocStats},
+    answers::{self, Verdict},
+    aoc_client::{AocClient, Outcome},
+    inputs, readme_stars,
+    records::{OutputFormat, PartRecord, Status, PARSE_STEP},
+    registry::Solution,
+    stats::{self, Stats},
+    submissions, ReadFileError, ANSI_ITALIC, ANSI_RESET,
+};
+use crate::Puzzle;
+
+/// Where a solution reads its input from.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum Input {
+    /// The downloaded puzzle input in `data/<year>/inputs`.
+    #[default]
+    Puzzle,
+    /// Any other file, e.g. a colleague's input or a hand-crafted edge case.
+    File(PathBuf),
+    Stdin,
+}
+
+impl Input {
+    /// Reads the input for a puzzle. The downloaded input is checked to look complete, see
+    /// [`inputs::read`].
+    pub fn read(&self, puzzle: Puzzle) -> Result<String, inputs::Error> {
+        match self {
+            Self::Puzzle => inputs::read(puzzle),
+            Self::File(path) => Ok(template::read_path(path)?),
+            Self::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|source| ReadFileError { path: None, source })?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+impl FromStr for Input {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "-" => Self::Stdin,
+            path => Self::File(path.into()),
+        })
+    }
+}
+
+/// Options that control how solution parts are run and reported.
+#[derive(Debug, Clone, Default)]
+pub struct RunOptions {
+    /// Bench each part instead of running it once.
+    pub time: bool,
+    /// Discard outlying samples while benching.
+    pub reject_outliers: bool,
+    pub format: OutputFormat,
+    /// The part to submit, if any.
+    pub submit: Option<u8>,
+    /// Submit even if the answer is known to be wrong.
+    pub force: bool,
+    /// Wait out rate limits and submit again instead of giving up.
+    pub wait: bool,
+    pub input: Input,
+    /// Don't print anything while running, the caller reports the records itself.
+    pub quiet: bool,
+}
+
+impl RunOptions {
+    /// Reads the options from the arguments passed to a solution binary,
+    /// e.g. `cargo run --bin 01 -- --time --submit 1`.
+    #[must_use]
+    pub fn from_args() -> Self {
+        let mut args = pico_args::Arguments::from_env();
+
+        let Ok(submit) = args.opt_value_from_str("--submit") else {
+            eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
+            process::exit(1);
+        };
+
+        let Ok(format) = args.opt_value_from_str("--format") else {
+            eprintln!("Unexpected command-line input. Format: cargo solve 1 --format json");
+            process::exit(1);
+        };
+
+        let Ok(input) = args.opt_value_from_str("--input") else {
+            eprintln!(
+                "Unexpected command-line input. Format: cargo solve 1 --input path/to/input.txt"
+            );
+            process::exit(1);
+        };
+
+        Self {
+            time: args.contains("--time"),
+            reject_outliers: args.contains("--reject-outliers"),
+            format: format.unwrap_or_default(),
+            submit,
+            force: args.contains("--force"),
+            wait: args.contains("--wait"),
+            input: input.unwrap_or_default(),
+            quiet: false,
+        }
+    }
+}
+
+/// Reads the input for a solution and runs both of its parts.
+pub fn run_day(
+    solution: &dyn Solution,
+    options: &RunOptions,
+) -> Result<Vec<PartRecord>, inputs::Error> {
+    let puzzle = solution.puzzle();
+    let input = options.input.read(puzzle)?;
+
+    if options.input == Input::Puzzle && inputs::is_modified(puzzle, &input) {
+        eprintln!(
+            "Warning: the input of day {} differs from the downloaded one, run `cargo download {} \
+             --year {}` to restore it.",
+            puzzle.day, puzzle.day, puzzle.year
+        );
+    }
+
+    Ok(solution.run(&input, options))
+}
+
+/// Run the `parse` function of a solution, whose output is shared by both parts.
+/// Returns the parsed input along with a record of how long parsing took, the parsed
+/// input is [`None`] if parsing panicked.
+pub fn run_parse<I: Clone, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    puzzle: Puzzle,
+    options: &RunOptions,
+) -> (Option<T>, PartRecord) {
+    let format = options.format;
+
+    let timed = run_timed(func, input, options, |_| {
+        if format == OutputFormat::Human && !options.quiet {
+            print!("Parse:");
+        }
+    });
+
+    let (parsed, record) = match timed {
+        Ok((parsed, stats, allocs)) => (
+            Some(parsed),
+            PartRecord {
+                year: puzzle.year,
+                day: puzzle.day,
+                part: PARSE_STEP,
+                answer: None,
+                status: Status::Solved,
+                stats,
+                expected: None,
+                error: None,
+                allocs,
+            },
+        ),
+        Err(message) => (None, panicked_record(puzzle, PARSE_STEP, None, message)),
+    };
+
+    if !options.quiet {
+        print_record(&record, format);
+    }
+    (parsed, record)
+}
 
-pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+/// Types that are printed and submitted as the answer of a part, see [`IntoAnswer`]. Implement
+/// it for your own answer types to return them in a [`Result`].
+pub trait Answer: Display {}
+
+macro_rules! impl_answer {
+    ($($t:ty),*) => {
+        $(impl Answer for $t {})*
+    };
+}
+
+impl_answer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, char, String, &str);
+
+/// What a part can return: `Option<T>`, `Result<T, E>` or `Result<Option<T>, E>`. A missing
+/// answer is shown as ✖, an error is shown with its message instead.
+pub trait IntoAnswer {
+    type Answer: Display;
+
+    /// Converts the return value of a part into its answer, or the message of its error. Errors
+    /// are formatted with `{:#}`, which includes their causes for e.g. `anyhow::Error`.
+    fn into_answer(self) -> Result<Option<Self::Answer>, String>;
+}
+
+impl<T: Display> IntoAnswer for Option<T> {
+    type Answer = T;
+
+    fn into_answer(self) -> Result<Option<T>, String> {
+        Ok(self)
+    }
+}
+
+impl<T: Answer, E: Display> IntoAnswer for Result<T, E> {
+    type Answer = T;
+
+    fn into_answer(self) -> Result<Option<T>, String> {
+        self.map(Some).map_err(|e| format!("{e:#}"))
+    }
+}
+
+impl<T: Display, E: Display> IntoAnswer for Result<Option<T>, E> {
+    type Answer = T;
+
+    fn into_answer(self) -> Result<Option<T>, String> {
+        self.map_err(|e| format!("{e:#}"))
+    }
+}
+
+/// The answer of a part as text, or the message of its error. Generated example tests compare
+/// this against the answers in the manifest, so they work with every return type of a part.
+///
+/// ```
+/// # use advent_of_code::template::runner::answer_text;
+/// fn part_one(input: &str) -> Result<u32, String> {
+///     input
+///         .trim()
+///         .parse()
+///         .map_err(|_| format!("not a number: {input}"))
+/// }
+///
+/// assert_eq!(answer_text(part_one("6")), Ok(Some("6".to_string())));
+/// assert_eq!(
+///     answer_text(part_one("x")),
+///     Err("not a number: x".to_string())
+/// );
+/// ```
+pub fn answer_text(result: impl IntoAnswer) -> Result<Option<String>, String> {
+    result
+        .into_answer()
+        .map(|answer| answer.map(|answer| answer.to_string()))
+}
+
+pub fn run_part<I: Clone, R: IntoAnswer>(
+    func: impl Fn(I) -> R,
+    input: I,
+    puzzle: Puzzle,
+    part: u8,
+    options: &RunOptions,
+) -> PartRecord {
     let part_str = format!("Part {part}");
+    let format = options.format;
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    let timed = run_timed(
+        |input| func(input).into_answer(),
+        input,
+        options,
+        |result| {
+            if format == OutputFormat::Human && !options.quiet {
+                print_result(
+                    &result.as_ref().ok().and_then(Option::as_ref),
+                    &part_str,
+                    "",
+                    "",
+                );
+            }
+        },
+    );
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    // accepted answers only apply to the puzzle input.
+    let expected = match answers::read(puzzle) {
+        Ok(answers) if options.input == Input::Puzzle => answers.get(part).map(String::from),
+        Ok(_) => None,
+        Err(e) => {
+            eprintln!("Failed to read accepted answers: {e}");
+            None
+        }
+    };
+
+    let (result, record) = match timed {
+        Ok((Ok(result), stats, allocs)) => {
+            let record = PartRecord {
+                year: puzzle.year,
+                day: puzzle.day,
+                part,
+                answer: result.as_ref().map(ToString::to_string),
+                status: if result.is_some() {
+                    Status::Solved
+                } else {
+                    Status::Unsolved
+                },
+                stats,
+                expected,
+                error: None,
+                allocs,
+            };
+            (result, record)
+        }
+        Ok((Err(error), stats, allocs)) => {
+            let record = PartRecord {
+                year: puzzle.year,
+                day: puzzle.day,
+                part,
+                answer: None,
+                status: Status::Errored,
+                stats,
+                expected,
+                error: Some(error),
+                allocs,
+            };
+            (None, record)
+        }
+        Err(message) => (None, panicked_record(puzzle, part, expected, message)),
+    };
+
+    if !options.quiet {
+        print_record(&record, format);
+    }
 
     if let Some(result) = result {
-        submit_result(result, day, part);
+        if options.submit == Some(part) {
+            if options.input == Input::Puzzle {
+                submit_result(result, puzzle, part, options);
+            } else {
+                eprintln!("Not submitting, only answers for the puzzle input can be submitted.");
+            }
+        }
+    }
+
+    record
+}
+
+fn panicked_record(
+    puzzle: Puzzle,
+    part: u8,
+    expected: Option<String>,
+    message: String,
+) -> PartRecord {
+    PartRecord {
+        year: puzzle.year,
+        day: puzzle.day,
+        part,
+        answer: None,
+        status: Status::Panicked,
+        stats: Stats::single(Duration::ZERO),
+        expected,
+        error: Some(message),
+        allocs: None,
+    }
+}
+
+/// Extracts the message passed to `panic!` from a panic payload.
+#[must_use]
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        (*message).to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic payload".to_string()
     }
 }
 
 /// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
 ///  1. in debug, the function is executed once.
 ///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+///
+/// Allocations are counted during the first execution if the `alloc_stats` feature is enabled.
+/// Returns the panic message if the function panicked.
 fn run_timed<I: Clone, T>(
     func: impl Fn(I) -> T,
     input: I,
+    options: &RunOptions,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+) -> Result<(T, Stats, Option<AllocStats>), String> {
+    let cloned = input.clone();
     let timer = Instant::now();
-    let result = func(input.clone());
+    let (result, allocs) =
+        alloc::measure(|| panic::catch_unwind(AssertUnwindSafe(|| func(cloned))));
     let base_time = timer.elapsed();
+    let result = result.map_err(|payload| panic_message(payload.as_ref()))?;
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
+    let stats = if options.time {
+        bench(func, input, &base_time, options)
     } else {
-        (base_time, 1)
+        Stats::single(base_time)
     };
 
-    (result, run.0, run.1)
+    Ok((result, stats, allocs))
 }
 
-fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
-    let mut stdout = stdout();
+/// Upper bound for the number of untimed iterations that run before sampling starts.
+const MAX_WARMUP_ITERATIONS: u128 = 100;
+
+fn bench<I: Clone, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: &Duration,
+    options: &RunOptions,
+) -> Stats {
+    if options.format == OutputFormat::Human && !options.quiet {
+        print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
+        let _ = stdout().flush();
+    }
 
-    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
-    let _ = stdout.flush();
+    let bench_iterations =
+        (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
 
-    let bench_iterations = cmp::min(
-        10000,
-        cmp::max(
-            Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10),
-            10,
-        ),
-    );
+    // warm up caches and branch predictors, these runs are not recorded.
+    let warmup_iterations = (bench_iterations / 10).clamp(1, MAX_WARMUP_ITERATIONS);
+    for _ in 0..warmup_iterations {
+        func(input.clone());
+    }
 
-    let mut timers: Vec<Duration> = vec![];
+    #[allow(clippy::cast_possible_truncation)]
+    let mut timers: Vec<Duration> = Vec::with_capacity(bench_iterations as usize);
 
     for _ in 0..bench_iterations {
         // need a clone here to make the borrow checker happy.
@@ -72,36 +436,94 @@ fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) ->
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    if options.reject_outliers {
+        timers = stats::reject_outliers(&timers);
+    }
+
+    Stats::from_samples(&timers).unwrap_or_else(|| Stats::single(*base_time))
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
+fn format_duration(stats: &Stats) -> String {
+    if stats.samples == 1 {
+        format!(" ({:.1?})", stats.median)
+    } else {
+        format!(
+            " ({:.1?} ± {:.1?}, min {:.1?}, p95 {:.1?}, max {:.1?} @ {} samples)",
+            stats.median, stats.std_dev, stats.min, stats.p95, stats.max, stats.samples
+        )
+    }
 }
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
-    if samples == 1 {
-        format!(" ({duration:.1?})")
+fn format_allocs(allocs: Option<&AllocStats>) -> String {
+    allocs.map_or_else(String::new, |allocs| format!(" [{allocs}]"))
+}
+
+fn format_verdict(record: &PartRecord) -> String {
+    match (record.verdict(), &record.expected) {
+        (Verdict::Differs, Some(expected)) if !expected.contains('\n') => {
+            format!(" {} (expected {expected})", Verdict::Differs.symbol())
+        }
+        (verdict, _) => format!(" {}", verdict.symbol()),
+    }
+}
+
+/// Prints the final output line(s) for a record in the given format.
+pub fn print_record(record: &PartRecord, format: OutputFormat) {
+    if format == OutputFormat::Json {
+        println!("{}", record.to_json_line());
+        return;
+    }
+
+    let label = if record.part == PARSE_STEP {
+        "Parse".to_string()
     } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+        format!("Part {}", record.part)
+    };
+
+    match (&record.status, &record.error) {
+        (Status::Panicked, error) => {
+            print!("\r");
+            println!(
+                "{label}: ✖ panicked: {}",
+                error.as_deref().unwrap_or("unknown panic payload")
+            );
+        }
+        (Status::Errored, error) => {
+            print!("\r");
+            println!(
+                "{label}: ✖ error: {}{}",
+                error.as_deref().unwrap_or_default(),
+                format_duration(&record.stats)
+            );
+        }
+        _ if record.part == PARSE_STEP => {
+            print!("\r");
+            println!(
+                "{label}:{}{}",
+                format_duration(&record.stats),
+                format_allocs(record.allocs.as_ref())
+            );
+        }
+        _ => print_result(
+            &record.answer,
+            &label,
+            &format_verdict(record),
+            &format!(
+                "{}{}",
+                format_duration(&record.stats),
+                format_allocs(record.allocs.as_ref())
+            ),
+        ),
     }
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
+fn print_result<T: Display>(result: &Option<T>, part: &str, verdict_str: &str, duration_str: &str) {
     let is_intermediate_result = duration_str.is_empty();
 
     match result {
         Some(result) => {
             if result.to_string().contains('\n') {
-                let str = format!("{part}: ▼ {duration_str}");
+                let str = format!("{part}: ▼{verdict_str} {duration_str}");
                 if is_intermediate_result {
                     print!("{str}");
                 } else {
@@ -110,7 +532,8 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
                     println!("{result}");
                 }
             } else {
-                let str = format!("{part}: {ANSI_BOLD}{result}{ANSI_RESET}{duration_str}");
+                let str =
+                    format!("{part}: {ANSI_BOLD}{result}{ANSI_RESET}{verdict_str}{duration_str}");
                 if is_intermediate_result {
                     print!("{str}");
                 } else {
@@ -124,50 +547,179 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
                 print!("{part}: ✖");
             } else {
                 print!("\r");
-                println!("{part}: ✖             ");
+                println!("{part}: ✖{verdict_str}             ");
             }
         }
     }
 }
 
-/// Parse the arguments passed to `solve` and try to submit one part of the solution if:
-///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
-    day: Day,
-    part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
-    let args: Vec<String> = env::args().collect();
+/// Submit one part of the solution to Advent of Code. Answers that earlier submissions ruled
+/// out are not sent, unless `force` is set. With `wait`, rate limits are waited out and the
+/// answer is submitted again.
+fn submit_result<T: Display>(result: T, puzzle: Puzzle, part: u8, options: &RunOptions) {
+    let answer = result.to_string();
+    let day = puzzle.day;
+    // json output is one record per line, keep the messages about the submission out of it.
+    let report = |message: &str| match options.format {
+        OutputFormat::Human => println!("{message}"),
+        OutputFormat::Json => eprintln!("{message}"),
+    };
 
-    if !args.contains(&"--submit".into()) {
-        return None;
-    }
+    match submissions::read(puzzle.year) {
+        Ok(ledger) => {
+            if !options.force {
+                if let Err(rejection) = ledger.check(day, part, &answer) {
+                    eprintln!("Not submitting: {rejection}. Append --force to submit anyway.");
+                    return;
+                }
+            }
 
-    if args.len() < 3 {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
+            if let Some(wait) = ledger.wait_remaining_now(day) {
+                if !options.wait {
+                    eprintln!(
+                        "Not submitting: Advent of Code asked to wait another {}s. Append --wait \
+                         to submit once the wait is over.",
+                        wait.as_secs()
+                    );
+                    return;
+                }
+                template::countdown(wait, "submitting");
+            }
+        }
+        Err(e) => eprintln!("Failed to read submission ledger: {e}"),
     }
 
-    let part_index = args.iter().position(|x| x == "--submit").unwrap() + 1;
+    let client = match AocClient::from_env() {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+
+    let submission = loop {
+        report("Submitting result...");
+
+        let submission = match client.submit(puzzle, part, &answer) {
+            Ok(submission) => submission,
+            Err(e) => {
+                eprintln!("Failed to submit answer: {e}");
+                process::exit(1);
+            }
+        };
+
+        let entry = submissions::Entry::new(
+            day,
+            part,
+            &answer,
+            submission.outcome.clone(),
+            submission.wait,
+        );
+        if let Err(e) = submissions::append(puzzle.year, &entry) {
+            eprintln!("Failed to record submission: {e}");
+        }
+
+        report(&submission.message);
 
-    let Ok(part_submit) = args[part_index].parse::<u8>() else {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
+        match submission.wait {
+            Some(wait) if submission.outcome == Outcome::RateLimited && options.wait => {
+                // the wait is rounded down to whole seconds, give the server a moment more.
+                template::countdown(wait + Duration::from_secs(1), "submitting");
+            }
+            Some(_) if submission.outcome == Outcome::RateLimited => {
+                eprintln!("Append --wait to submit again once the wait is over.");
+                break submission;
+            }
+            _ => break submission,
+        }
     };
 
-    if part_submit != part {
-        return None;
+    if submission.outcome == Outcome::Correct {
+        match answers::record(puzzle, part, &answer) {
+            Ok(()) => report(&format!(
+                "Recorded accepted answer in \"{}\".",
+                answers::get_path(puzzle).display()
+            )),
+            Err(e) => eprintln!("Failed to record accepted answer: {e}"),
+        }
+
+        match readme_stars::update() {
+            Ok(()) => report("Successfully updated README with stars."),
+            Err(e) => eprintln!("Failed to update readme with stars: {e}"),
+        }
     }
+}
 
-    if aoc_cli::check().is_err() {
-        eprintln!(
-            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to \
-             install it."
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::{answer_text, Input, IntoAnswer};
+    use crate::{day, year, Puzzle};
+
+    #[test]
+    fn parses_input() {
+        assert_eq!("-".parse::<Input>().unwrap(), Input::Stdin);
+        assert_eq!(
+            "edge-case.txt".parse::<Input>().unwrap(),
+            Input::File(PathBuf::from("edge-case.txt"))
+        );
+    }
+
+    #[test]
+    fn describes_missing_files() {
+        let err = Input::File("does/not/exist.txt".into())
+            .read(Puzzle::new(year!(2023), day!(1)))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "could not open \"does/not/exist.txt\": file does not exist"
+        );
+    }
+
+    #[test]
+    fn converts_answers() {
+        assert_eq!(Some(5).into_answer(), Ok(Some(5)));
+        assert_eq!(None::<u32>.into_answer(), Ok(None));
+        assert_eq!(Ok::<u64, String>(5).into_answer(), Ok(Some(5)));
+        assert_eq!(Ok::<Option<u64>, String>(None).into_answer(), Ok(None));
+        assert_eq!(
+            Err::<u32, _>("invalid card X").into_answer(),
+            Err("invalid card X".to_string())
         );
-        process::exit(1);
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    #[test]
+    fn converts_answers_to_text() {
+        assert_eq!(answer_text(Some(6)), Ok(Some("6".to_string())));
+        assert_eq!(
+            answer_text(Ok::<_, String>("abc")),
+            Ok(Some("abc".to_string()))
+        );
+        assert_eq!(answer_text(Ok::<Option<u8>, String>(None)), Ok(None));
+        assert_eq!(
+            answer_text(Err::<u32, _>("not L or R")),
+            Err("not L or R".to_string())
+        );
+    }
+
+    #[test]
+    fn formats_error_chains() {
+        struct Chain;
+
+        impl std::fmt::Display for Chain {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                if f.alternate() {
+                    f.write_str("could not parse hand: invalid card X")
+                } else {
+                    f.write_str("could not parse hand")
+                }
+            }
+        }
+
+        assert_eq!(
+            Err::<Option<String>, _>(Chain).into_answer(),
+            Err("could not parse hand: invalid card X".to_string())
+        );
+    }
 }
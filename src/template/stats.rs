@@ -0,0 +1,153 @@
+/// Summary statistics over the samples collected while benching a solution part.
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+    pub max: Duration,
+    pub mean: Duration,
+    pub std_dev: Duration,
+    pub samples: u128,
+}
+
+impl Stats {
+    /// Stats for a part that was executed exactly once.
+    #[must_use]
+    pub fn single(duration: Duration) -> Self {
+        Self {
+            min: duration,
+            median: duration,
+            p95: duration,
+            max: duration,
+            mean: duration,
+            std_dev: Duration::ZERO,
+            samples: 1,
+        }
+    }
+
+    /// Computes stats over a set of samples, returns [`None`] if there are none.
+    #[must_use]
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+
+        let len = sorted.len();
+        let median = if len % 2 == 1 {
+            sorted[len / 2]
+        } else {
+            (sorted[len / 2 - 1] + sorted[len / 2]) / 2
+        };
+
+        let mean = sorted.iter().map(Duration::as_nanos).sum::<u128>() / len as u128;
+        #[allow(clippy::cast_precision_loss)]
+        let variance = sorted
+            .iter()
+            .map(|x| (x.as_nanos() as f64 - mean as f64).powi(2))
+            .sum::<f64>()
+            / len as f64;
+
+        Some(Self {
+            min: sorted[0],
+            median,
+            p95: percentile(&sorted, 0.95),
+            max: sorted[len - 1],
+            #[allow(clippy::cast_possible_truncation)]
+            mean: Duration::from_nanos(mean as u64),
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            std_dev: Duration::from_nanos(variance.sqrt().round() as u64),
+            samples: len as u128,
+        })
+    }
+}
+
+/// Nearest-rank percentile of an already sorted, non-empty slice.
+fn percentile(sorted: &[Duration], p: f64) -> Duration {
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    let rank = (p * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+/// Drops samples outside of the Tukey fences (1.5 times the interquartile range below
+/// the first or above the third quartile), e.g. runs interrupted by a context switch.
+#[must_use]
+pub fn reject_outliers(samples: &[Duration]) -> Vec<Duration> {
+    if samples.len() < 4 {
+        return samples.to_vec();
+    }
+
+    let mut sorted = samples.to_vec();
+    sorted.sort_unstable();
+
+    let q1 = percentile(&sorted, 0.25);
+    let q3 = percentile(&sorted, 0.75);
+    let fence = (q3 - q1) * 3 / 2;
+
+    let low = q1.saturating_sub(fence);
+    let high = q3 + fence;
+
+    samples
+        .iter()
+        .copied()
+        .filter(|x| (low..=high).contains(x))
+        .collect()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{reject_outliers, Stats};
+
+    fn nanos(values: &[u64]) -> Vec<Duration> {
+        values.iter().copied().map(Duration::from_nanos).collect()
+    }
+
+    #[test]
+    fn stats_for_odd_sample_count() {
+        let stats = Stats::from_samples(&nanos(&[50, 10, 30, 20, 40])).unwrap();
+        assert_eq!(stats.min, Duration::from_nanos(10));
+        assert_eq!(stats.median, Duration::from_nanos(30));
+        assert_eq!(stats.p95, Duration::from_nanos(50));
+        assert_eq!(stats.max, Duration::from_nanos(50));
+        assert_eq!(stats.mean, Duration::from_nanos(30));
+        assert_eq!(stats.std_dev, Duration::from_nanos(14));
+        assert_eq!(stats.samples, 5);
+    }
+
+    #[test]
+    fn stats_for_even_sample_count() {
+        let stats = Stats::from_samples(&nanos(&[40, 10, 30, 20])).unwrap();
+        assert_eq!(stats.median, Duration::from_nanos(25));
+        assert_eq!(stats.p95, Duration::from_nanos(40));
+    }
+
+    #[test]
+    fn stats_for_no_samples() {
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn rejects_outliers() {
+        let samples = nanos(&[10, 11, 12, 10, 11, 12, 10, 11, 500]);
+        assert_eq!(
+            reject_outliers(&samples),
+            nanos(&[10, 11, 12, 10, 11, 12, 10, 11])
+        );
+    }
+
+    #[test]
+    fn keeps_small_sample_sets() {
+        let samples = nanos(&[10, 500]);
+        assert_eq!(reject_outliers(&samples), samples);
+    }
+}
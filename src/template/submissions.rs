@@ -0,0 +1,436 @@
+/// Module that keeps a ledger of every submitted answer of a year in
+/// `data/<year>/submissions.jsonl`.
+/// Rejected answers, and the bounds implied by "too high" and "too low" verdicts, are used to
+/// avoid sending answers that are known to be wrong.
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::PathBuf,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use tinyjson::JsonValue;
+
+use crate::{
+    template::{aoc_client::Outcome, get_year_path},
+    Day, Year,
+};
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "could not parse submission ledger: {e}"),
+            Error::IO(e) => write!(f, "could not access submission ledger: {e}"),
+        }
+    }
+}
+
+/// A single submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub day: Day,
+    pub part: u8,
+    pub answer: String,
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    pub verdict: Outcome,
+    /// Seconds Advent of Code asked to wait before submitting again.
+    pub wait_secs: Option<u64>,
+}
+
+impl Entry {
+    /// Creates an entry for an answer that was just submitted.
+    #[must_use]
+    pub fn new(day: Day, part: u8, answer: &str, verdict: Outcome, wait: Option<Duration>) -> Self {
+        Self {
+            day,
+            part,
+            answer: answer.to_string(),
+            timestamp: now(),
+            verdict,
+            wait_secs: wait.map(|wait| wait.as_secs()),
+        }
+    }
+
+    /// Whether Advent of Code checked the answer and found it to be wrong.
+    #[must_use]
+    pub fn is_rejected(&self) -> bool {
+        matches!(
+            self.verdict,
+            Outcome::Wrong | Outcome::TooHigh | Outcome::TooLow
+        )
+    }
+
+    fn to_json(&self) -> JsonValue {
+        let mut object: HashMap<String, JsonValue> = HashMap::new();
+        object.insert("day".into(), f64::from(self.day.into_inner()).into());
+        object.insert("part".into(), f64::from(self.part).into());
+        object.insert("answer".into(), self.answer.clone().into());
+        #[allow(clippy::cast_precision_loss)]
+        object.insert("timestamp".into(), (self.timestamp as f64).into());
+        object.insert(
+            "verdict".into(),
+            verdict_str(&self.verdict).to_string().into(),
+        );
+        #[allow(clippy::cast_precision_loss)]
+        object.insert(
+            "wait_secs".into(),
+            self.wait_secs
+                .map_or(JsonValue::Null, |wait| (wait as f64).into()),
+        );
+        object.into()
+    }
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn from_json(value: &JsonValue) -> Option<Self> {
+        let object: &HashMap<_, _> = value.get()?;
+
+        Some(Self {
+            day: Day::new(*object.get("day")?.get::<f64>()? as u8)?,
+            part: *object.get("part")?.get::<f64>()? as u8,
+            answer: object.get("answer")?.get::<String>()?.clone(),
+            timestamp: *object.get("timestamp")?.get::<f64>()? as u64,
+            verdict: verdict_from_str(object.get("verdict")?.get::<String>()?)?,
+            // entries written before wait times were recorded have no such field.
+            wait_secs: object
+                .get("wait_secs")
+                .and_then(|wait| wait.get::<f64>())
+                .map(|wait| *wait as u64),
+        })
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+fn verdict_str(verdict: &Outcome) -> &'static str {
+    match verdict {
+        Outcome::Correct => "correct",
+        Outcome::Wrong => "wrong",
+        Outcome::TooHigh => "too_high",
+        Outcome::TooLow => "too_low",
+        Outcome::RateLimited => "rate_limited",
+        Outcome::AlreadySolved => "already_solved",
+        Outcome::Unknown(_) => "unknown",
+    }
+}
+
+fn verdict_from_str(s: &str) -> Option<Outcome> {
+    match s {
+        "correct" => Some(Outcome::Correct),
+        "wrong" => Some(Outcome::Wrong),
+        "too_high" => Some(Outcome::TooHigh),
+        "too_low" => Some(Outcome::TooLow),
+        "rate_limited" => Some(Outcome::RateLimited),
+        "already_solved" => Some(Outcome::AlreadySolved),
+        "unknown" => Some(Outcome::Unknown(String::new())),
+        _ => None,
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// What is known about the answer of a part from "too high" and "too low" verdicts.
+/// Both bounds are exclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Bounds {
+    /// The highest answer that was too low.
+    pub lower: Option<i128>,
+    /// The lowest answer that was too high.
+    pub upper: Option<i128>,
+}
+
+/// Why an answer should not be submitted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Rejection {
+    /// The same answer was rejected before.
+    AlreadyRejected(Entry),
+    /// The answer is not below an answer that was too high.
+    AboveUpperBound(i128),
+    /// The answer is not above an answer that was too low.
+    BelowLowerBound(i128),
+}
+
+impl Display for Rejection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Rejection::AlreadyRejected(entry) => write!(
+                f,
+                "\"{}\" was already submitted and judged {}",
+                entry.answer, entry.verdict
+            ),
+            Rejection::AboveUpperBound(bound) => {
+                write!(
+                    f,
+                    "the answer must be lower than {bound}, which was too high"
+                )
+            }
+            Rejection::BelowLowerBound(bound) => {
+                write!(
+                    f,
+                    "the answer must be higher than {bound}, which was too low"
+                )
+            }
+        }
+    }
+}
+
+/// All recorded submissions, oldest first.
+#[derive(Debug, Clone, Default)]
+pub struct Ledger {
+    pub entries: Vec<Entry>,
+}
+
+impl Ledger {
+    fn entries_for(&self, day: Day, part: u8) -> impl Iterator<Item = &Entry> {
+        self.entries
+            .iter()
+            .filter(move |entry| entry.day == day && entry.part == part)
+    }
+
+    /// The known bounds for the answer of a part.
+    #[must_use]
+    pub fn bounds(&self, day: Day, part: u8) -> Bounds {
+        self.entries_for(day, part)
+            .fold(Bounds::default(), |mut bounds, entry| {
+                let Ok(answer) = entry.answer.trim().parse::<i128>() else {
+                    return bounds;
+                };
+
+                match entry.verdict {
+                    Outcome::TooHigh => {
+                        bounds.upper = Some(bounds.upper.map_or(answer, |u| u.min(answer)));
+                    }
+                    Outcome::TooLow => {
+                        bounds.lower = Some(bounds.lower.map_or(answer, |l| l.max(answer)));
+                    }
+                    _ => {}
+                }
+                bounds
+            })
+    }
+
+    /// Checks an answer against earlier submissions of the same part.
+    pub fn check(&self, day: Day, part: u8, answer: &str) -> Result<(), Rejection> {
+        if let Some(entry) = self
+            .entries_for(day, part)
+            .find(|entry| entry.is_rejected() && entry.answer == answer)
+        {
+            return Err(Rejection::AlreadyRejected(entry.clone()));
+        }
+
+        // answers that aren't numbers can't be compared against the bounds.
+        let Ok(value) = answer.trim().parse::<i128>() else {
+            return Ok(());
+        };
+
+        let bounds = self.bounds(day, part);
+        if let Some(upper) = bounds.upper.filter(|&upper| value >= upper) {
+            return Err(Rejection::AboveUpperBound(upper));
+        }
+        if let Some(lower) = bounds.lower.filter(|&lower| value <= lower) {
+            return Err(Rejection::BelowLowerBound(lower));
+        }
+
+        Ok(())
+    }
+
+    /// How much longer Advent of Code asked to wait before another answer for this day can be
+    /// submitted, given the current time in seconds since the unix epoch. The wait applies to
+    /// both parts, so it is taken from the most recent submission of either part that came with
+    /// one.
+    #[must_use]
+    pub fn wait_remaining(&self, day: Day, now: u64) -> Option<Duration> {
+        let entry = self
+            .entries
+            .iter()
+            .rev()
+            .find(|entry| entry.day == day && entry.wait_secs.is_some())?;
+        let ready_at = entry.timestamp + entry.wait_secs?;
+        (ready_at > now).then(|| Duration::from_secs(ready_at - now))
+    }
+
+    /// Like [`Ledger::wait_remaining`], for the current time.
+    #[must_use]
+    pub fn wait_remaining_now(&self, day: Day) -> Option<Duration> {
+        self.wait_remaining(day, now())
+    }
+}
+
+#[must_use]
+pub fn get_path(year: Year) -> PathBuf {
+    get_year_path(year).join("submissions.jsonl")
+}
+
+pub fn append(year: Year, entry: &Entry) -> Result<(), Error> {
+    let line = entry
+        .to_json()
+        .stringify()
+        .map_err(|e| Error::Parser(e.to_string()))?;
+
+    let path = get_path(year);
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{line}")?;
+    Ok(())
+}
+
+pub fn read(year: Year) -> Result<Ledger, Error> {
+    let path = get_path(year);
+    if !path.exists() {
+        return Ok(Ledger::default());
+    }
+
+    parse_ledger(&fs::read_to_string(path)?)
+}
+
+fn parse_ledger(s: &str) -> Result<Ledger, Error> {
+    let entries = s
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            line.parse()
+                .ok()
+                .as_ref()
+                .and_then(Entry::from_json)
+                .ok_or_else(|| Error::Parser(format!("malformed entry on line {}", i + 1)))
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(Ledger { entries })
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{parse_ledger, Bounds, Entry, Ledger, Rejection};
+    use crate::{day, template::aoc_client::Outcome};
+
+    fn entry(part: u8, answer: &str, verdict: Outcome) -> Entry {
+        Entry {
+            day: day!(5),
+            part,
+            answer: answer.into(),
+            timestamp: 1_701_388_800,
+            verdict,
+            wait_secs: None,
+        }
+    }
+
+    fn get_mock_ledger() -> Ledger {
+        Ledger {
+            entries: vec![
+                entry(1, "500", Outcome::TooHigh),
+                entry(1, "100", Outcome::TooLow),
+                entry(1, "400", Outcome::TooHigh),
+                entry(1, "250", Outcome::Wrong),
+                entry(1, "300", Outcome::RateLimited),
+                Entry {
+                    wait_secs: Some(60),
+                    ..entry(2, "7", Outcome::TooLow)
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn roundtrips_entries() {
+        let ledger = get_mock_ledger();
+        let s: String = ledger
+            .entries
+            .iter()
+            .map(|e| e.to_json().stringify().unwrap() + "\n")
+            .collect();
+        assert_eq!(parse_ledger(&s).unwrap().entries, ledger.entries);
+    }
+
+    #[test]
+    fn rejects_malformed_entries() {
+        assert_eq!(parse_ledger("{\"day\": 1}\n").is_err(), true);
+    }
+
+    #[test]
+    fn reads_entries_without_wait() {
+        let ledger = parse_ledger(
+            "{\"day\":5,\"part\":1,\"answer\":\"500\",\"timestamp\":1701388800,\"verdict\":\"\
+             too_high\"}\n",
+        )
+        .unwrap();
+        assert_eq!(ledger.entries, vec![entry(1, "500", Outcome::TooHigh)]);
+    }
+
+    #[test]
+    fn computes_remaining_wait() {
+        let ledger = get_mock_ledger();
+        assert_eq!(
+            ledger.wait_remaining(day!(5), 1_701_388_800 + 15),
+            Some(Duration::from_secs(45))
+        );
+        assert_eq!(ledger.wait_remaining(day!(5), 1_701_388_800 + 60), None);
+        assert_eq!(ledger.wait_remaining(day!(6), 1_701_388_800), None);
+    }
+
+    #[test]
+    fn derives_bounds() {
+        let ledger = get_mock_ledger();
+        assert_eq!(
+            ledger.bounds(day!(5), 1),
+            Bounds {
+                lower: Some(100),
+                upper: Some(400)
+            }
+        );
+        assert_eq!(ledger.bounds(day!(6), 1), Bounds::default());
+    }
+
+    #[test]
+    fn checks_answers() {
+        let ledger = get_mock_ledger();
+        assert_eq!(
+            ledger.check(day!(5), 1, "250"),
+            Err(Rejection::AlreadyRejected(entry(1, "250", Outcome::Wrong)))
+        );
+        assert_eq!(
+            ledger.check(day!(5), 1, "400"),
+            Err(Rejection::AlreadyRejected(entry(
+                1,
+                "400",
+                Outcome::TooHigh
+            )))
+        );
+        assert_eq!(
+            ledger.check(day!(5), 1, "450"),
+            Err(Rejection::AboveUpperBound(400))
+        );
+        assert_eq!(
+            ledger.check(day!(5), 1, "99"),
+            Err(Rejection::BelowLowerBound(100))
+        );
+        // rate-limited answers were never checked.
+        assert_eq!(ledger.check(day!(5), 1, "300"), Ok(()));
+        assert_eq!(ledger.check(day!(5), 2, "450"), Ok(()));
+        assert_eq!(ledger.check(day!(5), 1, "not a number"), Ok(()));
+    }
+}
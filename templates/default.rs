@@ -0,0 +1,27 @@
+// {{title}}
+advent_of_code::solution!({{year}}, {{day}});
+
+pub fn part_one(input: &str) -> Option<{{return_type}}> {
+    None
+}
+
+pub fn part_two(input: &str) -> Option<{{return_type}}> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, None);
+    }
+}
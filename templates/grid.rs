@@ -0,0 +1,62 @@
+// {{title}}
+advent_of_code::solution!({{year}}, {{day}}, parse);
+
+// the helpers are there for the parts to use, allow them to go unused until then.
+#[allow(dead_code)]
+pub struct Grid {
+    width: usize,
+    height: usize,
+    cells: Vec<u8>,
+}
+
+#[allow(dead_code)]
+impl Grid {
+    fn get(&self, x: usize, y: usize) -> Option<u8> {
+        (x < self.width && y < self.height).then(|| self.cells[y * self.width + x])
+    }
+
+    /// The orthogonal neighbours of a cell that are inside the grid.
+    fn neighbours(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        [(0, -1), (1, 0), (0, 1), (-1, 0)]
+            .into_iter()
+            .filter_map(move |(dx, dy)| {
+                let (x, y) = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+                (x < self.width && y < self.height).then_some((x, y))
+            })
+    }
+}
+
+pub fn parse(input: &str) -> Grid {
+    let width = input.lines().next().map_or(0, str::len);
+    let cells: Vec<u8> = input.lines().flat_map(str::bytes).collect();
+    Grid {
+        width,
+        height: cells.len() / width.max(1),
+        cells,
+    }
+}
+
+pub fn part_one(_grid: &Grid) -> Option<{{return_type}}> {
+    None
+}
+
+pub fn part_two(_grid: &Grid) -> Option<{{return_type}}> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let grid = parse(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(part_one(&grid), None);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let grid = parse(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(part_two(&grid), None);
+    }
+}
@@ -0,0 +1,44 @@
+// {{title}}
+advent_of_code::solution!({{year}}, {{day}}, parse);
+
+#[allow(dead_code)]
+pub struct Input {
+    lines: Vec<String>,
+}
+
+pub fn parse(input: &str) -> Input {
+    Input {
+        lines: input.lines().map(String::from).collect(),
+    }
+}
+
+pub fn part_one(_input: &Input) -> Option<{{return_type}}> {
+    None
+}
+
+pub fn part_two(_input: &Input) -> Option<{{return_type}}> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let example = advent_of_code::template::read_file("examples", PUZZLE);
+        assert_eq!(parse(&example).lines.len(), example.lines().count());
+    }
+
+    #[test]
+    fn test_part_one() {
+        let input = parse(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(part_one(&input), None);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = parse(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(part_two(&input), None);
+    }
+}
@@ -0,0 +1,43 @@
+// {{title}}
+advent_of_code::solution!({{year}}, {{day}});
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct State {}
+
+impl State {
+    fn parse(input: &str) -> Self {
+        State {}
+    }
+
+    /// Advances the simulation by one step.
+    fn step(&mut self) {}
+}
+
+pub fn part_one(input: &str) -> Option<{{return_type}}> {
+    let mut state = State::parse(input);
+    for _ in 0..10 {
+        state.step();
+    }
+    None
+}
+
+pub fn part_two(input: &str) -> Option<{{return_type}}> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, None);
+    }
+}